@@ -42,8 +42,7 @@ impl Canvas {
         }
     }
 
-    #[cfg(test)]
-    fn pixel_at(&self, x: usize, y: usize) -> &Color {
+    pub fn pixel_at(&self, x: usize, y: usize) -> &Color {
         &self.pixels[y * self.width + x]
     }
 }
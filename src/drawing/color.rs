@@ -3,14 +3,14 @@ use std::ops;
 
 use crate::geometry::Vector;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Color {
     value: Vector,
 }
 
-overload!((a: ?Color) + (b: ?Color) -> Color { Color { value: &a.value + &b.value } });
-overload!((a: ?Color) - (b: ?Color) -> Color { Color { value: &a.value - &b.value } });
-overload!((a: ?Color) * (c: f64) -> Color { Color { value: &a.value * c } });
+overload!((a: ?Color) + (b: ?Color) -> Color { Color { value: a.value + b.value } });
+overload!((a: ?Color) - (b: ?Color) -> Color { Color { value: a.value - b.value } });
+overload!((a: ?Color) * (c: f64) -> Color { Color { value: a.value * c } });
 overload!((a: ?Color) * (b: ?Color) -> Color { Color { value: mul_pairwise(&a.value, &b.value) } });
 
 impl Color {
@@ -44,12 +44,6 @@ impl Color {
     pub fn to_a(&self) -> [f64; 3] {
         [self.value.0, self.value.1, self.value.2]
     }
-
-    pub fn clone(&self) -> Color {
-        Color {
-            value: Vector(self.value.0, self.value.1, self.value.2),
-        }
-    }
 }
 
 impl PartialEq for Color {
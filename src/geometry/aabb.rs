@@ -0,0 +1,322 @@
+use super::{MatMul, Matrix, Point, Ray};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Aabb {
+    pub min: Point,
+    pub max: Point,
+}
+
+const EQUALITY_TOLERANCE: f64 = 1e-6;
+
+impl Aabb {
+    pub const EMPTY: Aabb = Aabb::new(
+        Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+        Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+    );
+
+    pub const INFINITE: Aabb = Aabb::new(
+        Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+        Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+    );
+
+    pub const fn new(min: Point, max: Point) -> Aabb {
+        Aabb { min, max }
+    }
+
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a Point>) -> Aabb {
+        points
+            .into_iter()
+            .fold(Aabb::EMPTY, |bounds, pt| bounds.union(&Aabb::new(*pt, *pt)))
+    }
+
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb::new(
+            Point::new(
+                self.min.x().min(other.min.x()),
+                self.min.y().min(other.min.y()),
+                self.min.z().min(other.min.z()),
+            ),
+            Point::new(
+                self.max.x().max(other.max.x()),
+                self.max.y().max(other.max.y()),
+                self.max.z().max(other.max.z()),
+            ),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x() > self.max.x() || self.min.y() > self.max.y() || self.min.z() > self.max.z()
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.min.x().is_finite()
+            && self.min.y().is_finite()
+            && self.min.z().is_finite()
+            && self.max.x().is_finite()
+            && self.max.y().is_finite()
+            && self.max.z().is_finite()
+    }
+
+    pub fn centroid(&self) -> Point {
+        Point::new(
+            (self.min.x() + self.max.x()) / 2.,
+            (self.min.y() + self.max.y()) / 2.,
+            (self.min.z() + self.max.z()) / 2.,
+        )
+    }
+
+    pub fn surface_area(&self) -> f64 {
+        if self.is_empty() {
+            return 0.;
+        }
+        let extent = self.max - self.min;
+        2. * (extent.0 * extent.1 + extent.1 * extent.2 + extent.2 * extent.0)
+    }
+
+    pub fn contains(&self, pt: &Point) -> bool {
+        (self.min.x()..=self.max.x()).contains(&pt.x())
+            && (self.min.y()..=self.max.y()).contains(&pt.y())
+            && (self.min.z()..=self.max.z()).contains(&pt.z())
+    }
+
+    pub fn intersect_with(&self, ray: &Ray) -> Option<(f64, f64)> {
+        if self.is_empty() {
+            return None;
+        }
+        let origin = [ray.origin.x(), ray.origin.y(), ray.origin.z()];
+        let direction = [ray.direction.0, ray.direction.1, ray.direction.2];
+        let min = [self.min.x(), self.min.y(), self.min.z()];
+        let max = [self.max.x(), self.max.y(), self.max.z()];
+
+        let mut entry = f64::NEG_INFINITY;
+        let mut exit = f64::INFINITY;
+        for axis in 0..3 {
+            if direction[axis] == 0. {
+                if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let inv_direction = direction[axis].recip();
+            let t0 = (min[axis] - origin[axis]) * inv_direction;
+            let t1 = (max[axis] - origin[axis]) * inv_direction;
+            let (near, far) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+            entry = entry.max(near);
+            exit = exit.min(far);
+        }
+
+        if entry > exit || exit < 0. {
+            None
+        } else {
+            Some((entry, exit))
+        }
+    }
+}
+
+impl MatMul<Aabb> for Aabb {
+    fn matmul(&self, m: &Matrix) -> Aabb {
+        if self.is_empty() {
+            return Aabb::EMPTY;
+        }
+        let mins = [self.min.x(), self.min.y(), self.min.z()];
+        let maxs = [self.max.x(), self.max.y(), self.max.z()];
+        let row_range = |row: usize| {
+            let mut lo = m.el(row, 3);
+            let mut hi = lo;
+            for col in 0..3 {
+                let factor = m.el(row, col);
+                if factor == 0. {
+                    continue;
+                }
+                let a = factor * mins[col];
+                let b = factor * maxs[col];
+                lo += a.min(b);
+                hi += a.max(b);
+            }
+            (lo, hi)
+        };
+
+        let (x0, x1) = row_range(0);
+        let (y0, y1) = row_range(1);
+        let (z0, z1) = row_range(2);
+        Aabb::new(Point::new(x0, y0, z0), Point::new(x1, y1, z1))
+    }
+}
+
+impl PartialEq for Aabb {
+    fn eq(&self, other: &Self) -> bool {
+        let coords = |b: &Aabb| {
+            [
+                b.min.x(),
+                b.min.y(),
+                b.min.z(),
+                b.max.x(),
+                b.max.y(),
+                b.max.z(),
+            ]
+        };
+        coords(self)
+            .iter()
+            .zip(coords(other).iter())
+            .all(|(a, b)| a == b || (a - b).abs() <= EQUALITY_TOLERANCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{PI, SQRT_2};
+
+    use super::*;
+    use crate::geometry::{UnitVector, Vector};
+
+    fn unit_cube() -> Aabb {
+        Aabb::new(Point::new(-1., -1., -1.), Point::new(1., 1., 1.))
+    }
+
+    #[test]
+    fn build_from_points() {
+        let points = [
+            Point::new(-5., 2., 0.),
+            Point::new(7., 0., -3.),
+            Point::new(1., 4., 6.),
+        ];
+
+        let bounds = Aabb::from_points(&points);
+        assert_eq!(
+            bounds,
+            Aabb::new(Point::new(-5., 0., -3.), Point::new(7., 4., 6.))
+        );
+    }
+
+    #[test]
+    fn empty_box_is_neutral_for_union() {
+        assert!(Aabb::EMPTY.is_empty());
+        assert_eq!(Aabb::EMPTY.union(&unit_cube()), unit_cube());
+        assert_eq!(unit_cube().union(&Aabb::EMPTY), unit_cube());
+    }
+
+    #[test]
+    fn union_of_two_boxes() {
+        let a = Aabb::new(Point::new(-5., -2., 0.), Point::new(7., 4., 4.));
+        let b = Aabb::new(Point::new(8., -7., -2.), Point::new(14., 2., 8.));
+
+        assert_eq!(
+            a.union(&b),
+            Aabb::new(Point::new(-5., -7., -2.), Point::new(14., 4., 8.))
+        );
+    }
+
+    #[test]
+    fn surface_area_and_centroid() {
+        let bounds = Aabb::new(Point::new(0., 0., 0.), Point::new(1., 2., 3.));
+
+        assert_eq!(bounds.surface_area(), 22.);
+        assert_eq!(bounds.centroid(), Point::new(0.5, 1., 1.5));
+        assert_eq!(Aabb::EMPTY.surface_area(), 0.);
+    }
+
+    #[test]
+    fn finite_and_infinite_boxes() {
+        assert!(unit_cube().is_finite());
+        assert!(!Aabb::INFINITE.is_finite());
+        assert!(Aabb::INFINITE.contains(&Point::new(1e300, -1e300, 0.)));
+    }
+
+    #[test]
+    fn translate_box() {
+        let bounds = unit_cube().matmul(&Matrix::translation(&Vector(1., 2., 3.)));
+        assert_eq!(
+            bounds,
+            Aabb::new(Point::new(0., 1., 2.), Point::new(2., 3., 4.))
+        );
+    }
+
+    #[test]
+    fn rotated_box_is_conservative() {
+        let bounds = unit_cube().matmul(&Matrix::rotate_y(PI / 4.));
+        assert_eq!(
+            bounds,
+            Aabb::new(
+                Point::new(-SQRT_2, -1., -SQRT_2),
+                Point::new(SQRT_2, 1., SQRT_2)
+            )
+        );
+    }
+
+    #[test]
+    fn transform_box_with_infinite_extents() {
+        let plane = Aabb::new(
+            Point::new(f64::NEG_INFINITY, 0., f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, 0., f64::INFINITY),
+        );
+
+        let translated = plane.matmul(&Matrix::translation(&Vector(0., 2., 0.)));
+        assert_eq!(
+            translated,
+            Aabb::new(
+                Point::new(f64::NEG_INFINITY, 2., f64::NEG_INFINITY),
+                Point::new(f64::INFINITY, 2., f64::INFINITY)
+            )
+        );
+
+        let tilted = plane.matmul(&Matrix::rotate_x(PI / 4.));
+        assert_eq!(tilted, Aabb::INFINITE);
+    }
+
+    mod ray_intersection {
+        use super::*;
+
+        #[test]
+        fn ray_hits_box_from_outside() {
+            let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+            assert_eq!(unit_cube().intersect_with(&ray), Some((4., 6.)));
+        }
+
+        #[test]
+        fn ray_originates_inside_box() {
+            let ray = Ray::new(Point::ZERO, UnitVector::X.flip());
+            assert_eq!(unit_cube().intersect_with(&ray), Some((-1., 1.)));
+        }
+
+        #[test]
+        fn ray_misses_box() {
+            let ray = Ray::new(Point::new(2., 0., -5.), UnitVector::Z);
+            assert_eq!(unit_cube().intersect_with(&ray), None);
+
+            let diagonal = Ray::new(Point::new(-3., 0., 0.), Vector(1., 1., 0.).normalize());
+            assert_eq!(unit_cube().intersect_with(&diagonal), None);
+        }
+
+        #[test]
+        fn empty_box_is_never_hit() {
+            let ray = Ray::new(Point::ZERO, Vector(1., 1., 1.).normalize());
+            assert_eq!(Aabb::EMPTY.intersect_with(&ray), None);
+        }
+
+        #[test]
+        fn box_behind_ray_is_missed() {
+            let ray = Ray::new(Point::new(0., 0., 5.), UnitVector::Z);
+            assert_eq!(unit_cube().intersect_with(&ray), None);
+        }
+
+        #[test]
+        fn ray_parallel_to_slab() {
+            let inside = Ray::new(Point::new(0.5, 0., -5.), UnitVector::Z);
+            let outside = Ray::new(Point::new(1.5, 0., -5.), UnitVector::Z);
+
+            assert_eq!(unit_cube().intersect_with(&inside), Some((4., 6.)));
+            assert_eq!(unit_cube().intersect_with(&outside), None);
+        }
+
+        #[test]
+        fn ray_hits_unbounded_box() {
+            let ray = Ray::new(Point::new(0., 5., 0.), UnitVector::Y.flip());
+            let plane = Aabb::new(
+                Point::new(f64::NEG_INFINITY, 0., f64::NEG_INFINITY),
+                Point::new(f64::INFINITY, 0., f64::INFINITY),
+            );
+
+            assert_eq!(plane.intersect_with(&ray), Some((5., 5.)));
+        }
+    }
+}
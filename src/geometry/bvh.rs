@@ -0,0 +1,253 @@
+use super::{Aabb, Point, Ray};
+
+pub struct Bvh {
+    nodes: Vec<Node>,
+    unbounded: Vec<usize>,
+}
+
+enum Node {
+    Leaf { bounds: Aabb, items: Vec<usize> },
+    Branch { bounds: Aabb, children: [usize; 2] },
+}
+
+struct Item {
+    index: usize,
+    bounds: Aabb,
+    centroid: Point,
+}
+
+impl Bvh {
+    const MAX_LEAF_SIZE: usize = 4;
+
+    pub fn build(bounds: &[Aabb]) -> Bvh {
+        let (bounded, unbounded): (Vec<_>, Vec<_>) =
+            (0..bounds.len()).partition(|&i| bounds[i].is_finite());
+        let items = bounded
+            .into_iter()
+            .map(|index| Item {
+                index,
+                bounds: bounds[index],
+                centroid: bounds[index].centroid(),
+            })
+            .collect();
+
+        let mut bvh = Bvh {
+            nodes: vec![],
+            unbounded,
+        };
+        bvh.build_node(items);
+        bvh
+    }
+
+    pub fn closest_hit<T>(
+        &self,
+        ray: &Ray,
+        mut intersect: impl FnMut(usize) -> Option<(f64, T)>,
+    ) -> Option<(usize, T)> {
+        let mut closest: Option<(usize, f64, T)> = None;
+        let mut try_item = |index: usize, closest: &mut Option<(usize, f64, T)>| {
+            if let Some((t, hit)) = intersect(index) {
+                if Self::is_closer(index, t, closest) {
+                    *closest = Some((index, t, hit))
+                }
+            }
+        };
+
+        for &index in &self.unbounded {
+            try_item(index, &mut closest);
+        }
+
+        let mut stack = vec![0];
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            let Some((entry, _)) = node.bounds().intersect_with(ray) else {
+                continue;
+            };
+            if closest.as_ref().is_some_and(|(_, t, _)| entry > *t) {
+                continue;
+            }
+            match node {
+                Node::Leaf { items, .. } => {
+                    for &index in items {
+                        try_item(index, &mut closest);
+                    }
+                }
+                Node::Branch { children, .. } => stack.extend(children.iter().rev()),
+            }
+        }
+        closest.map(|(index, _, hit)| (index, hit))
+    }
+
+    pub fn for_each_candidate(&self, ray: &Ray, max_distance: f64, mut visit: impl FnMut(usize)) {
+        self.unbounded.iter().for_each(|&index| visit(index));
+
+        let mut stack = vec![0];
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            match node.bounds().intersect_with(ray) {
+                Some((entry, _)) if entry <= max_distance => {}
+                _ => continue,
+            }
+            match node {
+                Node::Leaf { items, .. } => items.iter().for_each(|&index| visit(index)),
+                Node::Branch { children, .. } => stack.extend(children.iter()),
+            }
+        }
+    }
+
+    fn is_closer<T>(index: usize, t: f64, closest: &Option<(usize, f64, T)>) -> bool {
+        match closest {
+            None => true,
+            Some((best_index, best_t, _)) => t < *best_t || (t == *best_t && index < *best_index),
+        }
+    }
+
+    fn build_node(&mut self, mut items: Vec<Item>) -> usize {
+        let bounds = items
+            .iter()
+            .fold(Aabb::EMPTY, |acc, item| acc.union(&item.bounds));
+        let node_index = self.nodes.len();
+
+        if items.len() <= Self::MAX_LEAF_SIZE {
+            let items = items.into_iter().map(|item| item.index).collect();
+            self.nodes.push(Node::Leaf { bounds, items });
+            return node_index;
+        }
+
+        let axis = Self::split_axis(&items);
+        items.sort_by(|a, b| axis(&a.centroid).total_cmp(&axis(&b.centroid)));
+        let right_items = items.split_off(items.len() / 2);
+
+        self.nodes.push(Node::Branch {
+            bounds,
+            children: [0, 0],
+        });
+        let left = self.build_node(items);
+        let right = self.build_node(right_items);
+        if let Node::Branch { children, .. } = &mut self.nodes[node_index] {
+            *children = [left, right];
+        }
+        node_index
+    }
+
+    fn split_axis(items: &[Item]) -> fn(&Point) -> f64 {
+        let centroids = Aabb::from_points(items.iter().map(|item| &item.centroid));
+        let extent = centroids.max - centroids.min;
+        if extent.0 >= extent.1 && extent.0 >= extent.2 {
+            Point::x
+        } else if extent.1 >= extent.2 {
+            Point::y
+        } else {
+            Point::z
+        }
+    }
+}
+
+impl Node {
+    fn bounds(&self) -> &Aabb {
+        match self {
+            Node::Leaf { bounds, .. } | Node::Branch { bounds, .. } => bounds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{UnitVector, Vector};
+
+    fn unit_box_at(x: f64, y: f64, z: f64) -> Aabb {
+        let center = Point::new(x, y, z);
+        Aabb::new(
+            center - Vector(0.5, 0.5, 0.5),
+            center + Vector(0.5, 0.5, 0.5),
+        )
+    }
+
+    fn hit_box_entry(boxes: &[Aabb], ray: &Ray) -> impl FnMut(usize) -> Option<(f64, f64)> {
+        let boxes = boxes.to_vec();
+        let ray = ray.clone();
+        move |index| {
+            boxes[index]
+                .intersect_with(&ray)
+                .map(|(entry, _)| (entry, entry))
+        }
+    }
+
+    #[test]
+    fn empty_hierarchy_has_no_hits() {
+        let bvh = Bvh::build(&[]);
+        let ray = Ray::new(Point::ZERO, UnitVector::Z);
+
+        assert_eq!(bvh.closest_hit(&ray, |_| Some((1., 1.))), None);
+    }
+
+    #[test]
+    fn find_closest_of_many_items() {
+        let boxes: Vec<Aabb> = (0..20)
+            .map(|i| unit_box_at(0., 0., i as f64 * 2.))
+            .collect();
+        let bvh = Bvh::build(&boxes);
+        let ray = Ray::new(Point::new(0., 0., 11.), UnitVector::Z);
+
+        let hit = bvh.closest_hit(&ray, hit_box_entry(&boxes, &ray));
+        assert_eq!(hit, Some((6, 0.5)));
+    }
+
+    #[test]
+    fn items_off_the_ray_are_never_tested() {
+        let boxes: Vec<Aabb> = (0..20)
+            .map(|i| unit_box_at(i as f64 * 2., 0., 0.))
+            .collect();
+        let bvh = Bvh::build(&boxes);
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+
+        let mut tested = vec![];
+        let hit = bvh.closest_hit(&ray, |index| {
+            tested.push(index);
+            boxes[index]
+                .intersect_with(&ray)
+                .map(|(entry, _)| (entry, entry))
+        });
+        assert_eq!(hit, Some((0, 4.5)));
+        assert!(tested.len() <= Bvh::MAX_LEAF_SIZE);
+    }
+
+    #[test]
+    fn visit_candidates_up_to_maximum_distance() {
+        let boxes: Vec<Aabb> = (0..20)
+            .map(|i| unit_box_at(0., 0., i as f64 * 2.))
+            .chain([unit_box_at(5., 0., 0.), Aabb::INFINITE])
+            .collect();
+        let bvh = Bvh::build(&boxes);
+        let ray = Ray::new(Point::new(0., 0., -1.), UnitVector::Z);
+
+        let mut visited = vec![];
+        bvh.for_each_candidate(&ray, 4., |index| visited.push(index));
+        visited.sort();
+
+        assert!(visited.starts_with(&[0, 1, 2]));
+        assert!(visited.ends_with(&[21]));
+        assert!(!visited.contains(&10));
+    }
+
+    #[test]
+    fn unbounded_items_are_always_tested() {
+        let boxes = vec![unit_box_at(100., 0., 0.), Aabb::INFINITE];
+        let bvh = Bvh::build(&boxes);
+        let ray = Ray::new(Point::ZERO, UnitVector::Y);
+
+        let hit = bvh.closest_hit(&ray, |index| if index == 1 { Some((3., 3.)) } else { None });
+        assert_eq!(hit, Some((1, 3.)));
+    }
+
+    #[test]
+    fn equal_distances_resolve_to_lowest_index() {
+        let boxes = vec![unit_box_at(0., 0., 0.); 10];
+        let bvh = Bvh::build(&boxes);
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+
+        let hit = bvh.closest_hit(&ray, hit_box_entry(&boxes, &ray));
+        assert_eq!(hit, Some((0, 4.5)));
+    }
+}
@@ -10,11 +10,11 @@ pub struct Point(Vector);
 
 overload!(- (a: ?Vector) -> Vector { Vector(-a.0, -a.1, -a.2) });
 
-overload!((a: ?Vector) + (b: ?Point) -> Point { Point::from(a + &b.0) });
+overload!((a: ?Vector) + (b: ?Point) -> Point { Point::from(a + b.0) });
 overload!((a: ?Vector) + (b: ?Vector) -> Vector { Vector(a.0 + b.0, a.1 + b.1, a.2 + b.2) });
 overload!((a: ?Point) + (b: ?Vector) -> Point { b + a });
 
-overload!((a: ?Point) - (b: ?Point) -> Vector { &a.0 - &b.0 });
+overload!((a: ?Point) - (b: ?Point) -> Vector { a.0 - b.0 });
 overload!((a: ?Point) - (b: ?Vector) -> Point { -b + a });
 overload!((a: ?Vector) - (b: ?Vector) -> Vector { -b + a });
 
@@ -158,8 +158,8 @@ mod tests {
         let left = Point::new(3., -2., 5.);
         let right = Vector(-2., 3., 1.);
 
-        let sum_left = &left + &right;
-        let sum_right = &right + &left;
+        let sum_left = left + right;
+        let sum_right = right + left;
 
         assert_eq!(sum_left, Point::new(1., 1., 6.));
         assert_eq!(sum_left, sum_right);
@@ -170,8 +170,8 @@ mod tests {
         let left = Vector(3., -2., 5.);
         let right = Vector(-2., 3., 1.);
 
-        let sum_left = &left + &right;
-        let sum_right = &right + &left;
+        let sum_left = left + right;
+        let sum_right = right + left;
 
         assert_eq!(sum_left, Vector(1., 1., 6.));
         assert_eq!(sum_left, sum_right);
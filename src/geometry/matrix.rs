@@ -2,16 +2,24 @@ use overload::overload;
 use std::ops::{self, Deref};
 use super::{UnitVector, Point, Vector};
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub struct Matrix([f64; 16]);
 
+const EQUALITY_TOLERANCE: f64 = 1e-6;
+const SINGULARITY_TOLERANCE: f64 = 1e-12;
+
 pub trait MatMul<T> {
     fn matmul(&self, m: &Matrix) -> T;
 }
 
-overload!((a: ?Matrix) * (b: ?Matrix) -> Matrix { a.matmul(&b) });
-overload!((a: ?Matrix) * (p: ?Point) -> Point { p.matmul(&a) });
-overload!((a: ?Matrix) * (v: ?Vector) -> Vector { v.matmul(&a) });
+overload!((a: &Matrix) * (b: &Matrix) -> Matrix { a.matmul(b) });
+overload!((a: &Matrix) * (b: Matrix) -> Matrix { a.matmul(&b) });
+overload!((a: Matrix) * (b: &Matrix) -> Matrix { a.matmul(b) });
+overload!((a: Matrix) * (b: Matrix) -> Matrix { a.matmul(&b) });
+overload!((a: &Matrix) * (p: ?Point) -> Point { p.matmul(a) });
+overload!((a: Matrix) * (p: ?Point) -> Point { p.matmul(&a) });
+overload!((a: &Matrix) * (v: ?Vector) -> Vector { v.matmul(a) });
+overload!((a: Matrix) * (v: ?Vector) -> Vector { v.matmul(&a) });
 
 
 impl Matrix {
@@ -71,6 +79,48 @@ impl Matrix {
         ])
     }
 
+    pub fn rotate_z(angle: f64) -> Matrix {
+        Matrix([
+            angle.cos(), -angle.sin(), 0., 0.,
+            angle.sin(),  angle.cos(), 0., 0., 
+                     0.,           0., 1., 0., 
+                     0.,           0., 0., 1.
+        ])
+    }
+
+    pub fn rotation(axis: &UnitVector, angle: f64) -> Matrix {
+        let Vector(x, y, z) = axis.v();
+        let (s, c) = angle.sin_cos();
+        let t = 1. - c;
+        Matrix([
+            t * x * x + c,     t * x * y - s * z, t * x * z + s * y, 0.,
+            t * x * y + s * z, t * y * y + c,     t * y * z - s * x, 0.,
+            t * x * z - s * y, t * y * z + s * x, t * z * z + c,     0.,
+            0.,                0.,                0.,                1.
+        ])
+    }
+
+    pub fn shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Matrix {
+        Matrix([
+            1., xy, xz, 0.,
+            yx, 1., yz, 0.,
+            zx, zy, 1., 0.,
+            0., 0., 0., 1.
+        ])
+    }
+
+    pub fn orientation(from: &Point, to: &Point, up: &Vector) -> Matrix {
+        let view_z = (to - from).normalize();
+        let up_norm = up.normalize();
+        let view_x = up_norm.cross(&view_z).normalize();
+        let view_y = view_z.cross(&view_x).normalize();
+        Matrix::from_vectors(&view_x, &view_y, &view_z)
+    }
+
+    pub fn look_at(from: &Point, to: &Point, up: &Vector) -> Matrix {
+        Matrix::translation(from) * Matrix::orientation(from, to, up)
+    }
+
     pub fn from_vectors(x: &UnitVector, y: &UnitVector, z: &UnitVector) -> Matrix {
         #[rustfmt::skip]
         let matrix = [
@@ -86,9 +136,75 @@ impl Matrix {
         arg.matmul(self)
     }
 
-    fn el(&self, row: usize, col: usize) -> f64 {
+    pub fn transpose(&self) -> Matrix {
+        let mut result = Matrix::zero();
+        for row in 0..4 {
+            for col in 0..4 {
+                result.0[col * 4 + row] = self.el(row, col)
+            }
+        }
+        result
+    }
+
+    pub fn determinant(&self) -> f64 {
+        (0..4).map(|col| self.el(0, col) * self.cofactor(0, col)).sum()
+    }
+
+    pub fn minor(&self, row: usize, col: usize) -> f64 {
+        let rows = Self::other_indices(row);
+        let cols = Self::other_indices(col);
+        let sub = |r: usize, c: usize| self.el(rows[r], cols[c]);
+
+        sub(0, 0) * (sub(1, 1) * sub(2, 2) - sub(1, 2) * sub(2, 1))
+            - sub(0, 1) * (sub(1, 0) * sub(2, 2) - sub(1, 2) * sub(2, 0))
+            + sub(0, 2) * (sub(1, 0) * sub(2, 1) - sub(1, 1) * sub(2, 0))
+    }
+
+    pub fn cofactor(&self, row: usize, col: usize) -> f64 {
+        let minor = self.minor(row, col);
+        if (row + col).is_multiple_of(2) { minor } else { -minor }
+    }
+
+    pub fn inverse(&self) -> Option<Matrix> {
+        let det = self.determinant();
+        // Hadamard's bound: |det| never exceeds the product of the row lengths,
+        // so the ratio measures degeneracy independently of the matrix scale
+        let row_lengths: f64 = (0..4)
+            .map(|row| (0..4).map(|col| self.el(row, col).powi(2)).sum::<f64>().sqrt())
+            .product();
+        if det == 0. || det.abs() <= SINGULARITY_TOLERANCE * row_lengths {
+            return None;
+        }
+
+        let mut result = Matrix::zero();
+        for row in 0..4 {
+            for col in 0..4 {
+                result.0[col * 4 + row] = self.cofactor(row, col) / det
+            }
+        }
+        Some(result)
+    }
+
+    pub(crate) fn el(&self, row: usize, col: usize) -> f64 {
         self.0[row * 4 + col]
     }
+
+    fn other_indices(index: usize) -> [usize; 3] {
+        let mut result = [0; 3];
+        for (slot, i) in (0..4).filter(|&i| i != index).enumerate() {
+            result[slot] = i
+        }
+        result
+    }
+}
+
+impl PartialEq for Matrix {
+    fn eq(&self, other: &Self) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| (a - b).abs() <= EQUALITY_TOLERANCE)
+    }
 }
 
 impl MatMul<Matrix> for Matrix {
@@ -147,14 +263,69 @@ mod transform_tests {
     fn translate_vector_does_not_affect_it() {
         let transform = Matrix::translation(&Vector(5., -3., 2.));
         let v = Vector(-3., 4., 5.);
-        assert_eq!(transform * &v, v)
+        assert_eq!(transform * v, v)
     }
 
 }
 
+#[cfg(test)]
+mod rotation_tests {
+    use std::f64::consts::{PI, SQRT_2};
+
+    use crate::geometry::{Matrix, Point, UnitVector, Vector};
+
+    #[test]
+    fn rotate_point_around_z() {
+        let p = Point::new(0., 1., 0.);
+        assert_eq!(Matrix::rotate_z(PI / 4.) * p, Point::new(-SQRT_2 / 2., SQRT_2 / 2., 0.));
+        assert_eq!(Matrix::rotate_z(PI / 2.) * p, Point::new(-1., 0., 0.));
+    }
+
+    #[test]
+    fn rotation_around_principal_axes_matches_fixed_axis_rotations() {
+        let angle = PI / 3.;
+        assert_eq!(Matrix::rotation(&UnitVector::X, angle), Matrix::rotate_x(angle));
+        assert_eq!(Matrix::rotation(&UnitVector::Y, angle), Matrix::rotate_y(angle));
+        assert_eq!(Matrix::rotation(&UnitVector::Z, angle), Matrix::rotate_z(angle));
+    }
+
+    #[test]
+    fn rotate_around_diagonal_axis_cycles_coordinate_axes() {
+        let axis = Vector(1., 1., 1.).normalize();
+        let m = Matrix::rotation(&axis, 2. * PI / 3.);
+
+        assert_eq!(&m * Vector(1., 0., 0.), Vector(0., 1., 0.));
+        assert_eq!(&m * Vector(0., 1., 0.), Vector(0., 0., 1.));
+        assert_eq!(&m * Vector(1., 1., 1.), Vector(1., 1., 1.));
+    }
+
+    #[test]
+    fn shear_point() {
+        let p = Point::new(2., 3., 4.);
+
+        assert_eq!(Matrix::shearing(1., 0., 0., 0., 0., 0.) * p, Point::new(5., 3., 4.));
+        assert_eq!(Matrix::shearing(0., 1., 0., 0., 0., 0.) * p, Point::new(6., 3., 4.));
+        assert_eq!(Matrix::shearing(0., 0., 1., 0., 0., 0.) * p, Point::new(2., 5., 4.));
+        assert_eq!(Matrix::shearing(0., 0., 0., 1., 0., 0.) * p, Point::new(2., 7., 4.));
+        assert_eq!(Matrix::shearing(0., 0., 0., 0., 1., 0.) * p, Point::new(2., 3., 6.));
+        assert_eq!(Matrix::shearing(0., 0., 0., 0., 0., 1.) * p, Point::new(2., 3., 7.));
+    }
+
+    #[test]
+    fn look_at_maps_z_axis_towards_target() {
+        let from = Point::new(1., 2., 3.);
+        let to = Point::new(1., 2., 8.);
+        let m = Matrix::look_at(&from, &to, &Vector(0., 1., 0.));
+
+        assert_eq!(&m * Point::ZERO, from);
+        assert_eq!(&m * Point::new(0., 0., 5.), to);
+        assert_eq!(&m * Vector(0., 1., 0.), Vector(0., 1., 0.));
+    }
+}
+
 #[cfg(test)]
 mod matrix_operations {
-    use super::Matrix;
+    use super::{Matrix, Vector};
 
     #[test]
     fn multiply_matrices() {
@@ -178,4 +349,135 @@ mod matrix_operations {
             16., 26.,  46.,  42.
         ]))
     }
-}
\ No newline at end of file
+    #[test]
+    fn transpose_matrix() {
+        let a = Matrix::new([
+            0., 9., 3., 0., 
+            9., 8., 0., 8., 
+            1., 8., 5., 3., 
+            0., 0., 5., 8.
+        ]);
+
+        assert_eq!(a.transpose(), Matrix::new([
+            0., 9., 1., 0., 
+            9., 8., 8., 0., 
+            3., 0., 5., 5., 
+            0., 8., 3., 8.
+        ]));
+        assert_eq!(Matrix::IDENTITY.transpose(), Matrix::IDENTITY);
+    }
+
+    #[test]
+    fn minors_and_cofactors() {
+        let a = Matrix::new([
+            -2., -8.,  3.,  5., 
+            -3.,  1.,  7.,  3., 
+             1.,  2., -9.,  6., 
+            -6.,  7.,  7., -9.
+        ]);
+
+        assert_eq!(a.minor(0, 0), 690.);
+        assert_eq!(a.cofactor(0, 0), 690.);
+        assert_eq!(a.minor(0, 1), -447.);
+        assert_eq!(a.cofactor(0, 1), 447.);
+        assert_eq!(a.cofactor(0, 2), 210.);
+        assert_eq!(a.cofactor(0, 3), 51.);
+    }
+
+    #[test]
+    fn determinant_of_4x4_matrix() {
+        let a = Matrix::new([
+            -2., -8.,  3.,  5., 
+            -3.,  1.,  7.,  3., 
+             1.,  2., -9.,  6., 
+            -6.,  7.,  7., -9.
+        ]);
+
+        assert_eq!(a.determinant(), -4071.);
+    }
+
+    #[test]
+    fn singular_matrix_has_no_inverse() {
+        let a = Matrix::new([
+            -4.,  2., -2., -3., 
+             9.,  6.,  2.,  6., 
+             0., -5.,  1., -5., 
+             0.,  0.,  0.,  0.
+        ]);
+
+        assert_eq!(a.determinant(), 0.);
+        assert_eq!(a.inverse(), None);
+    }
+
+    #[test]
+    fn nearly_singular_matrix_has_no_inverse() {
+        let a = Matrix::new([
+            1.,    0., 0., 0.,
+            1., 1e-14, 0., 0.,
+            0.,    0., 1., 0.,
+            0.,    0., 0., 1.
+        ]);
+
+        assert_ne!(a.determinant(), 0.);
+        assert_eq!(a.inverse(), None);
+    }
+
+    #[test]
+    fn small_uniform_scale_has_an_inverse() {
+        let a = Matrix::diag(&Vector(1e-5, 1e-5, 1e-5));
+
+        let inverse = a.inverse().unwrap();
+        assert_eq!(inverse, Matrix::diag(&Vector(1e5, 1e5, 1e5)));
+    }
+
+    #[test]
+    fn invert_matrix() {
+        let a = Matrix::new([
+            -5.,  2.,  6., -8., 
+             1., -5.,  1.,  8., 
+             7.,  7., -6., -7., 
+             1., -3.,  7.,  4.
+        ]);
+
+        let b = a.inverse().unwrap();
+        assert_eq!(a.determinant(), 532.);
+        assert_eq!(b.el(3, 2), -160. / 532.);
+        assert_eq!(b.el(2, 3), 105. / 532.);
+        assert_eq!(&a * &b, Matrix::IDENTITY);
+        assert_eq!(b * a, Matrix::IDENTITY);
+    }
+
+    #[test]
+    fn multiply_product_by_inverse_restores_matrix() {
+        let a = Matrix::new([
+             3., -9.,  7.,  3., 
+             3., -8.,  2., -9., 
+            -4.,  4.,  4.,  1., 
+            -6.,  5., -1.,  1.
+        ]);
+        let b = Matrix::new([
+            8.,  2., 2., 2., 
+            3., -1., 7., 0., 
+            7.,  0., 5., 4., 
+            6., -2., 0., 5.
+        ]);
+
+        let c = &a * &b;
+        assert_eq!(c * b.inverse().unwrap(), a);
+    }
+
+    #[test]
+    fn inverse_of_transposed_is_transposed_inverse() {
+        let a = Matrix::new([
+            -5.,  2.,  6., -8., 
+             1., -5.,  1.,  8., 
+             7.,  7., -6., -7., 
+             1., -3.,  7.,  4.
+        ]);
+
+        assert_eq!(
+            a.transpose().inverse().unwrap(),
+            a.inverse().unwrap().transpose()
+        );
+    }
+}
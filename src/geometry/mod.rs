@@ -1,9 +1,17 @@
+mod aabb;
+mod bvh;
 mod coords;
 mod matrix;
 mod normal;
+mod quaternion;
 mod ray;
+mod solvers;
 
+pub use aabb::Aabb;
+pub use bvh::Bvh;
 pub use coords::*;
 pub use matrix::{MatMul, Matrix};
 pub use normal::Normal;
+pub use quaternion::Quaternion;
 pub use ray::Ray;
+pub use solvers::{solve_cubic_equation, solve_quadratic_equation, solve_quartic_equation};
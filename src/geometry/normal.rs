@@ -6,6 +6,8 @@ use super::{Point, UnitVector, Vector};
 pub struct Normal(UnitVector);
 
 impl Normal {
+    const SURFACE_OFFSET: f64 = 1e-8;
+
     pub fn dot(&self, v: &Vector) -> f64 {
         self.0.dot(v)
     }
@@ -19,7 +21,15 @@ impl Normal {
     }
 
     pub fn over_point(&self, point: &Point) -> Point {
-        point + self.0.deref() * 1e-8
+        point + self.0.deref() * Self::SURFACE_OFFSET
+    }
+
+    pub fn under_point(&self, point: &Point) -> Point {
+        point - self.0.deref() * Self::SURFACE_OFFSET
+    }
+
+    pub fn v(&self) -> Vector {
+        self.0.v()
     }
 }
 
@@ -32,7 +42,7 @@ impl From<&Vector> for Normal {
 #[cfg(test)]
 mod tests {
     use super::Normal;
-    use crate::geometry::Vector;
+    use crate::geometry::{Point, Vector};
     use std::f64::consts::SQRT_2;
 
     impl Normal {
@@ -49,6 +59,16 @@ mod tests {
         assert_eq!(*n.0, Vector(SQRT_2 / 2., SQRT_2 / 2., 0.));
     }
 
+    #[test]
+    fn over_and_under_points_lie_on_opposite_sides_of_surface() {
+        let n = Normal::new(0., 1., 0.);
+        let point = Point::new(1., 0., 1.);
+
+        assert_eq!(n.over_point(&point).y(), Normal::SURFACE_OFFSET);
+        assert_eq!(n.under_point(&point).y(), -Normal::SURFACE_OFFSET);
+        assert!(n.under_point(&point).y() < 0.);
+    }
+
     #[test]
     fn reflect_vector_at_45_degrees_from_horizontal_surface() {
         let v = Vector(1., 1., 0.);
@@ -0,0 +1,221 @@
+use overload::overload;
+use std::ops;
+
+use super::{Matrix, Vector};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+overload!((a: ?Quaternion) * (b: ?Quaternion) -> Quaternion {
+    Quaternion::new(
+        a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+    )
+});
+
+const EQUALITY_TOLERANCE: f64 = 1e-6;
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion::new(1., 0., 0., 0.);
+
+    const NLERP_THRESHOLD: f64 = 0.9995;
+
+    pub const fn new(w: f64, x: f64, y: f64, z: f64) -> Quaternion {
+        Quaternion { w, x, y, z }
+    }
+
+    pub fn from_axis_angle(axis: &Vector, angle: f64) -> Quaternion {
+        let axis = axis.normalize();
+        let (s, c) = (angle / 2.).sin_cos();
+        Quaternion::new(c, axis.0 * s, axis.1 * s, axis.2 * s)
+    }
+
+    pub fn from_euler(roll: f64, pitch: f64, yaw: f64) -> Quaternion {
+        let qx = Self::from_axis_angle(&Vector(1., 0., 0.), roll);
+        let qy = Self::from_axis_angle(&Vector(0., 1., 0.), pitch);
+        let qz = Self::from_axis_angle(&Vector(0., 0., 1.), yaw);
+        qz * qy * qx
+    }
+
+    pub fn dot(&self, other: &Quaternion) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quaternion {
+        self.scale(self.magnitude().recip())
+    }
+
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let from = self.normalize();
+        let mut to = other.normalize();
+        let mut cos_theta = from.dot(&to);
+        if cos_theta < 0. {
+            to = to.scale(-1.);
+            cos_theta = -cos_theta;
+        }
+
+        if cos_theta > Self::NLERP_THRESHOLD {
+            return from.scale(1. - t).add(&to.scale(t)).normalize();
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let from_weight = ((1. - t) * theta).sin() / sin_theta;
+        let to_weight = (t * theta).sin() / sin_theta;
+        from.scale(from_weight).add(&to.scale(to_weight))
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        let Quaternion { w, x, y, z } = self.normalize();
+        #[rustfmt::skip]
+        let matrix = Matrix::new([
+            1. - 2. * (y * y + z * z), 2. * (x * y - w * z),      2. * (x * z + w * y),      0.,
+            2. * (x * y + w * z),      1. - 2. * (x * x + z * z), 2. * (y * z - w * x),      0.,
+            2. * (x * z - w * y),      2. * (y * z + w * x),      1. - 2. * (x * x + y * y), 0.,
+            0.,                        0.,                        0.,                        1.,
+        ]);
+        matrix
+    }
+
+    fn scale(&self, c: f64) -> Quaternion {
+        Quaternion::new(self.w * c, self.x * c, self.y * c, self.z * c)
+    }
+
+    fn add(&self, other: &Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w + other.w,
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+        )
+    }
+}
+
+impl PartialEq for Quaternion {
+    fn eq(&self, other: &Self) -> bool {
+        let diff = self.add(&other.scale(-1.));
+        diff.magnitude() <= EQUALITY_TOLERANCE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{PI, SQRT_2};
+
+    use super::*;
+    use crate::geometry::{Point, UnitVector};
+
+    #[test]
+    fn create_from_axis_angle() {
+        let q = Quaternion::from_axis_angle(&Vector(0., 0., 3.), PI / 2.);
+        assert_eq!(q, Quaternion::new(SQRT_2 / 2., 0., 0., SQRT_2 / 2.));
+        assert!((q.magnitude() - 1.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn identity_produces_identity_matrix() {
+        assert_eq!(Quaternion::IDENTITY.to_matrix(), Matrix::IDENTITY);
+    }
+
+    #[test]
+    fn convert_to_rotation_matrix() {
+        let angle = PI / 3.;
+        let axis = Vector(1., 2., 3.);
+
+        let q = Quaternion::from_axis_angle(&axis, angle);
+        assert_eq!(q.to_matrix(), Matrix::rotation(&axis.normalize(), angle));
+    }
+
+    #[test]
+    fn non_unit_quaternion_is_normalized_before_conversion() {
+        let q = Quaternion::new(2., 0., 2., 0.);
+        assert_eq!(q.to_matrix(), Matrix::rotate_y(PI / 2.));
+    }
+
+    #[test]
+    fn compose_rotations() {
+        let qx = Quaternion::from_axis_angle(&UnitVector::X, PI / 2.);
+        let qy = Quaternion::from_axis_angle(&UnitVector::Y, PI / 2.);
+
+        let composed = (qy * qx).to_matrix();
+        assert_eq!(
+            composed,
+            Matrix::rotate_y(PI / 2.) * Matrix::rotate_x(PI / 2.)
+        );
+    }
+
+    #[test]
+    fn euler_angles_apply_roll_then_pitch_then_yaw() {
+        let q = Quaternion::from_euler(PI / 2., PI / 2., 0.);
+
+        let p = q.to_matrix() * Point::new(0., 1., 0.);
+        assert_eq!(p, Point::new(1., 0., 0.));
+    }
+
+    #[test]
+    fn conjugate_reverses_rotation() {
+        let q = Quaternion::from_axis_angle(&Vector(1., 1., 0.), 1.2);
+        assert_eq!(q * q.conjugate(), Quaternion::IDENTITY);
+    }
+
+    #[test]
+    fn slerp_end_points() {
+        let a = Quaternion::IDENTITY;
+        let b = Quaternion::from_axis_angle(&UnitVector::Z, PI / 2.);
+
+        assert_eq!(a.slerp(&b, 0.), a);
+        assert_eq!(a.slerp(&b, 1.), b);
+    }
+
+    #[test]
+    fn slerp_halfway_is_half_the_angle() {
+        let a = Quaternion::IDENTITY;
+        let b = Quaternion::from_axis_angle(&UnitVector::Z, PI / 2.);
+
+        let halfway = a.slerp(&b, 0.5);
+        assert_eq!(
+            halfway,
+            Quaternion::from_axis_angle(&UnitVector::Z, PI / 4.)
+        );
+    }
+
+    #[test]
+    fn slerp_takes_the_shortest_path() {
+        let a = Quaternion::IDENTITY;
+        let b = Quaternion::from_axis_angle(&UnitVector::Z, PI / 2.).scale(-1.);
+
+        let halfway = a.slerp(&b, 0.5);
+        assert_eq!(
+            halfway,
+            Quaternion::from_axis_angle(&UnitVector::Z, PI / 4.)
+        );
+    }
+
+    #[test]
+    fn slerp_between_nearly_equal_rotations() {
+        let a = Quaternion::from_axis_angle(&UnitVector::X, 0.1);
+        let b = Quaternion::from_axis_angle(&UnitVector::X, 0.1001);
+
+        let halfway = a.slerp(&b, 0.5);
+        assert_eq!(
+            halfway,
+            Quaternion::from_axis_angle(&UnitVector::X, 0.10005)
+        );
+        assert!((halfway.magnitude() - 1.).abs() < 1e-9);
+    }
+}
@@ -16,18 +16,18 @@ impl Ray {
 
     pub fn between(origin: &Point, dest: &Point) -> Ray {
         let direction = (dest - origin).normalize();
-        Self::new(origin.clone(), direction)
+        Self::new(*origin, direction)
     }
 
     pub fn position(&self, distance: f64) -> Point {
-        &self.origin + &self.direction * distance
+        self.origin + self.direction * distance
     }
 }
 
 impl MatMul<Ray> for Ray {
     fn matmul(&self, m: &Matrix) -> Ray {
         Ray {
-            origin: m * &self.origin,
+            origin: m * self.origin,
             direction: m * self.direction,
         }
     }
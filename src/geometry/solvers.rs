@@ -0,0 +1,249 @@
+use std::f64::consts::PI;
+
+const EPSILON: f64 = 1e-12;
+
+pub fn solve_quadratic_equation(a: f64, b: f64, c: f64) -> Vec<f64> {
+    // A tiny but non-zero `a` still has two finite roots; only treat the
+    // equation as linear when it really is one
+    if a == 0. {
+        return if b == 0. { vec![] } else { vec![-c / b] };
+    }
+
+    let d = b * b - 4. * a * c;
+    if d < 0. {
+        return vec![];
+    }
+    let q = -0.5 * (b + b.signum() * d.sqrt());
+    if q == 0. {
+        return vec![0., 0.];
+    }
+    let (x1, x2) = (q / a, c / q);
+    if x1 <= x2 {
+        vec![x1, x2]
+    } else {
+        vec![x2, x1]
+    }
+}
+
+pub fn solve_cubic_equation(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+    if a.abs() < EPSILON {
+        return solve_quadratic_equation(b, c, d);
+    }
+    let (b, c, d) = (b / a, c / a, d / a);
+    let shift = -b / 3.;
+    let p = c - b * b / 3.;
+    let q = 2. * b * b * b / 27. - b * c / 3. + d;
+
+    let discriminant = q * q / 4. + p * p * p / 27.;
+    let mut roots = if discriminant > 0. || p >= 0. {
+        let sqrt_d = discriminant.max(0.).sqrt();
+        let u = (-q / 2. + sqrt_d).cbrt();
+        let v = (-q / 2. - sqrt_d).cbrt();
+        vec![u + v + shift]
+    } else {
+        let r = (-p / 3.).sqrt();
+        let phi = (-q / (2. * r * r * r)).clamp(-1., 1.).acos();
+        (0..3)
+            .map(|k| 2. * r * ((phi - 2. * PI * k as f64) / 3.).cos() + shift)
+            .collect()
+    };
+    for root in roots.iter_mut() {
+        *root = polish_root(&[1., b, c, d], *root);
+    }
+    roots.sort_by(f64::total_cmp);
+    roots
+}
+
+pub fn solve_quartic_equation(a: f64, b: f64, c: f64, d: f64, e: f64) -> Vec<f64> {
+    if a.abs() < EPSILON {
+        return solve_cubic_equation(b, c, d, e);
+    }
+    let (b, c, d, e) = (b / a, c / a, d / a, e / a);
+
+    // Substituting x = y - b/4 gives the depressed quartic y^4 + p*y^2 + q*y + r
+    let shift = -b / 4.;
+    let b2 = b * b;
+    let p = c - 3. * b2 / 8.;
+    let q = d - b * c / 2. + b2 * b / 8.;
+    let r = e - b * d / 4. + b2 * c / 16. - 3. * b2 * b2 / 256.;
+    let scale = 1. + p.abs() + r.abs().sqrt();
+
+    let ys = if q.abs() < EPSILON * scale * scale * scale {
+        solve_tolerant_quadratic(1., p, r)
+            .into_iter()
+            .filter(|&z| z >= -EPSILON * scale)
+            .flat_map(|z| {
+                let y = z.max(0.).sqrt();
+                [-y, y]
+            })
+            .collect()
+    } else {
+        // Any positive root m of the resolvent cubic splits the quartic into two quadratics
+        let m = solve_cubic_equation(1., p, p * p / 4. - r, -q * q / 8.)
+            .into_iter()
+            .fold(0., f64::max);
+        let s = (2. * m).sqrt();
+        let mut ys = solve_tolerant_quadratic(1., s, p / 2. + m - q / (2. * s));
+        ys.extend(solve_tolerant_quadratic(1., -s, p / 2. + m + q / (2. * s)));
+        ys
+    };
+
+    let mut roots: Vec<f64> = ys
+        .into_iter()
+        .map(|y| polish_root(&[1., b, c, d, e], y + shift))
+        .collect();
+    roots.sort_by(f64::total_cmp);
+    roots
+}
+
+// Accepts slightly negative discriminants as double roots so that tangent
+// solutions survive rounding errors in the coefficients.
+fn solve_tolerant_quadratic(a: f64, b: f64, c: f64) -> Vec<f64> {
+    let d = b * b - 4. * a * c;
+    if d < 0. && d > -1e-9 * (b * b + (4. * a * c).abs()).max(EPSILON) {
+        return vec![-b / (2. * a); 2];
+    }
+    solve_quadratic_equation(a, b, c)
+}
+
+fn polish_root(coefficients: &[f64], mut x: f64) -> f64 {
+    let evaluate = |x: f64| {
+        coefficients
+            .iter()
+            .fold((0., 0.), |(value, derivative), &k| {
+                (value * x + k, derivative * x + value)
+            })
+    };
+    for _ in 0..4 {
+        let (value, derivative) = evaluate(x);
+        if derivative == 0. {
+            break;
+        }
+        let next = x - value / derivative;
+        if evaluate(next).0.abs() >= value.abs() {
+            break;
+        }
+        x = next;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_roots(actual: Vec<f64>, expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len(), "roots {actual:?}");
+        for (root, expected) in actual.iter().zip(expected) {
+            assert!(
+                (root - expected).abs() <= 1e-9 * expected.abs().max(1.),
+                "roots {actual:?}, expected {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn two_distinct_roots_in_ascending_order() {
+        assert_roots(solve_quadratic_equation(1., -3., 2.), &[1., 2.]);
+        assert_roots(solve_quadratic_equation(-1., 3., -2.), &[1., 2.]);
+    }
+
+    #[test]
+    fn double_root_is_reported_twice() {
+        assert_roots(solve_quadratic_equation(1., -2., 1.), &[1., 1.]);
+        assert_roots(solve_quadratic_equation(1., 0., 0.), &[0., 0.]);
+    }
+
+    #[test]
+    fn no_real_roots() {
+        assert!(solve_quadratic_equation(1., 0., 1.).is_empty());
+    }
+
+    #[test]
+    fn degenerate_equation_is_solved_as_linear() {
+        assert_roots(solve_quadratic_equation(0., 2., -1.), &[0.5]);
+        assert!(solve_quadratic_equation(0., 0., 1.).is_empty());
+    }
+
+    #[test]
+    fn tiny_leading_coefficient_keeps_both_roots() {
+        assert_roots(solve_quadratic_equation(2.5e-13, 0., -1.), &[-2e6, 2e6]);
+        assert_roots(solve_quadratic_equation(1e-15, 2., -1.), &[-2e15, 0.5]);
+    }
+
+    #[test]
+    fn small_root_keeps_its_precision() {
+        let roots = solve_quadratic_equation(1., 1e8, 1.);
+        assert_roots(roots.clone(), &[-1e8, -1e-8]);
+        assert_eq!(roots[1], -1e-8);
+    }
+
+    #[test]
+    fn cubic_with_three_real_roots() {
+        assert_roots(solve_cubic_equation(1., -6., 11., -6.), &[1., 2., 3.]);
+        assert_roots(solve_cubic_equation(2., 0., -2., 0.), &[-1., 0., 1.]);
+    }
+
+    #[test]
+    fn cubic_with_one_real_root() {
+        assert_roots(solve_cubic_equation(1., 0., 1., -2.), &[1.]);
+        assert_roots(solve_cubic_equation(1., -3., 3., -1.), &[1.]);
+    }
+
+    #[test]
+    fn degenerate_cubic_is_solved_as_quadratic() {
+        assert_roots(solve_cubic_equation(0., 1., -3., 2.), &[1., 2.]);
+    }
+
+    #[test]
+    fn quartic_with_four_real_roots() {
+        // (x - 1)(x - 2)(x - 3)(x - 4)
+        assert_roots(
+            solve_quartic_equation(1., -10., 35., -50., 24.),
+            &[1., 2., 3., 4.],
+        );
+        // 2(x + 3)(x + 0.5)(x - 0.25)(x - 7)
+        assert_roots(
+            solve_quartic_equation(2., -7.5, -44.25, -9.5, 5.25),
+            &[-3., -0.5, 0.25, 7.],
+        );
+    }
+
+    #[test]
+    fn biquadratic_quartic() {
+        // (x^2 - 1)(x^2 - 4)
+        assert_roots(
+            solve_quartic_equation(1., 0., -5., 0., 4.),
+            &[-2., -1., 1., 2.],
+        );
+        // x^4 + 1 has no real roots
+        assert!(solve_quartic_equation(1., 0., 0., 0., 1.).is_empty());
+    }
+
+    #[test]
+    fn quartic_with_two_real_roots() {
+        // (x^2 + 1)(x - 1)(x + 2)
+        assert_roots(solve_quartic_equation(1., 1., -1., 1., -2.), &[-2., 1.]);
+    }
+
+    #[test]
+    fn quartic_double_roots_are_kept() {
+        // (x - 1)^2 (x - 3)^2
+        let roots = solve_quartic_equation(1., -8., 22., -24., 9.);
+        assert_eq!(roots.len(), 4, "roots {roots:?}");
+        assert!(roots[..2].iter().all(|root| (root - 1.).abs() < 1e-6));
+        assert!(roots[2..].iter().all(|root| (root - 3.).abs() < 1e-6));
+    }
+
+    #[test]
+    fn quartic_with_widely_spread_roots() {
+        // (x - 0.001)(x - 1)(x - 10)(x - 1000)
+        let roots = solve_quartic_equation(1., -1011.001, 11011.011, -10011.01, 10.);
+        assert_roots(roots, &[0.001, 1., 10., 1000.]);
+    }
+
+    #[test]
+    fn degenerate_quartic_is_solved_as_cubic() {
+        assert_roots(solve_quartic_equation(0., 1., -6., 11., -6.), &[1., 2., 3.]);
+    }
+}
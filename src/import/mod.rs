@@ -0,0 +1,122 @@
+mod mtl;
+mod obj;
+mod ply;
+mod stl;
+
+pub use mtl::{parse_mtl, read_mtl, MtlLibrary, MtlMaterial};
+pub use obj::{parse_obj, read_obj, ObjFile};
+pub use ply::{parse_ply, read_ply, PlyFile};
+pub use stl::{parse_stl, read_stl, StlFile};
+
+use std::io::{Error, ErrorKind, Result};
+
+fn line_error(line: usize, message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, format!("line {line}: {message}"))
+}
+
+fn parse_numbers(line_number: usize, args: &[&str], count: usize) -> Result<Vec<f64>> {
+    if args.len() < count {
+        return Err(line_error(
+            line_number,
+            format!("expected {count} values, found {}", args.len()),
+        ));
+    }
+    args.iter()
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| line_error(line_number, format!("expected a number, found '{token}'")))
+        })
+        .collect()
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+struct Tokens<'a> {
+    tokens: Vec<(usize, &'a str)>,
+    pos: usize,
+    last_line: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(content: &'a str, first_line: usize) -> Tokens<'a> {
+        let tokens: Vec<(usize, &str)> = content
+            .lines()
+            .enumerate()
+            .flat_map(|(index, line)| {
+                line.split_whitespace()
+                    .map(move |token| (first_line + index, token))
+            })
+            .collect();
+        let last_line = first_line + content.lines().count().saturating_sub(1);
+        Tokens {
+            tokens,
+            pos: 0,
+            last_line,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).map(|(_, token)| *token)
+    }
+
+    fn next(&mut self) -> Result<(usize, &'a str)> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| line_error(self.last_line, "unexpected end of file".to_string()))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, keyword: &str) -> Result<()> {
+        let (line, token) = self.next()?;
+        if token != keyword {
+            return Err(line_error(
+                line,
+                format!("expected '{keyword}', found '{token}'"),
+            ));
+        }
+        Ok(())
+    }
+
+    fn number(&mut self) -> Result<f64> {
+        let (line, token) = self.next()?;
+        token
+            .parse()
+            .map_err(|_| line_error(line, format!("expected a number, found '{token}'")))
+    }
+}
+
+struct Bytes<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Bytes<'a> {
+    fn new(data: &'a [u8]) -> Bytes<'a> {
+        Bytes { data, pos: 0 }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self.data.get(self.pos..self.pos + N).ok_or_else(|| {
+            invalid_data(format!(
+                "unexpected end of data at byte {}",
+                self.pos.min(self.data.len())
+            ))
+        })?;
+        self.pos += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn f32(&mut self) -> Result<f64> {
+        Ok(f32::from_le_bytes(self.take()?) as f64)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take()?))
+    }
+}
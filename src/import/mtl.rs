@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Result;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::drawing::Color;
+use crate::patterns::{ImageTexture, UvPattern};
+use crate::ppm::read_ppm;
+use crate::shapes::Material;
+
+use super::{line_error, parse_numbers};
+
+#[derive(Clone)]
+pub struct MtlMaterial {
+    pub color: Color,
+    pub ambient: f64,
+    pub diffuse: f64,
+    pub specular: f64,
+    pub shininess: i32,
+    pub transparency: f64,
+    pub refractive_index: f64,
+    pub texture: Option<Arc<dyn UvPattern>>,
+}
+
+pub struct MtlLibrary {
+    pub materials: HashMap<String, MtlMaterial>,
+    pub warnings: Vec<String>,
+}
+
+pub fn read_mtl(filename: &str) -> Result<MtlLibrary> {
+    let path = Path::new(filename);
+    parse_mtl(
+        &fs::read_to_string(path)?,
+        path.parent().unwrap_or(Path::new("")),
+    )
+}
+
+pub fn parse_mtl(content: &str, texture_dir: &Path) -> Result<MtlLibrary> {
+    let mut library = MtlLibrary {
+        materials: HashMap::new(),
+        warnings: vec![],
+    };
+    let mut current: Option<String> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.split('#').next().unwrap_or_default();
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            continue;
+        };
+        let args: Vec<&str> = tokens.collect();
+
+        if keyword == "newmtl" {
+            let name = args.join(" ");
+            library
+                .materials
+                .insert(name.clone(), MtlMaterial::default());
+            current = Some(name);
+            continue;
+        }
+        let Some(material) = current
+            .as_ref()
+            .and_then(|name| library.materials.get_mut(name))
+        else {
+            return Err(line_error(
+                line_number,
+                format!("'{keyword}' appears before any 'newmtl'"),
+            ));
+        };
+
+        match keyword {
+            "Kd" => {
+                material.color = parse_color(line_number, &args)?;
+                material.diffuse = 1.;
+            }
+            "Ka" => material.ambient = parse_intensity(line_number, &args)?,
+            "Ks" => material.specular = parse_intensity(line_number, &args)?,
+            "Ns" => material.shininess = parse_number(line_number, &args)?.round() as i32,
+            "d" => material.transparency = 1. - parse_number(line_number, &args)?,
+            "Tr" => material.transparency = parse_number(line_number, &args)?,
+            "Ni" => material.refractive_index = parse_number(line_number, &args)?,
+            "map_Kd" => {
+                let Some(filename) = args.last() else {
+                    return Err(line_error(
+                        line_number,
+                        "missing texture file name".to_string(),
+                    ));
+                };
+                let path = texture_dir.join(filename);
+                match read_ppm(&path.to_string_lossy()) {
+                    Ok(canvas) => material.texture = Some(Arc::new(ImageTexture::new(canvas))),
+                    Err(err) => library.warnings.push(format!(
+                        "line {line_number}: cannot read texture '{}': {err}",
+                        path.display()
+                    )),
+                }
+            }
+            _ => library.warnings.push(format!(
+                "line {line_number}: unsupported statement '{keyword}'"
+            )),
+        }
+    }
+    Ok(library)
+}
+
+impl MtlMaterial {
+    pub fn to_material(&self) -> Material {
+        let mut material = Material::default_with_color(self.color.clone());
+        material.ambient = self.ambient;
+        material.diffuse = self.diffuse;
+        material.specular = self.specular;
+        material.shininess = self.shininess;
+        material.transparency = self.transparency;
+        material.refractive_index = self.refractive_index;
+        material
+    }
+}
+
+impl Default for MtlMaterial {
+    fn default() -> Self {
+        let material = Material::default();
+        MtlMaterial {
+            color: material.color,
+            ambient: material.ambient,
+            diffuse: material.diffuse,
+            specular: material.specular,
+            shininess: material.shininess,
+            transparency: material.transparency,
+            refractive_index: material.refractive_index,
+            texture: None,
+        }
+    }
+}
+
+fn parse_number(line_number: usize, args: &[&str]) -> Result<f64> {
+    Ok(parse_numbers(line_number, args, 1)?[0])
+}
+
+fn parse_intensity(line_number: usize, args: &[&str]) -> Result<f64> {
+    Ok(parse_numbers(line_number, args, 3)?.iter().sum::<f64>() / 3.)
+}
+
+fn parse_color(line_number: usize, args: &[&str]) -> Result<Color> {
+    let rgb = parse_numbers(line_number, args, 3)?;
+    Ok(Color::new(rgb[0], rgb[1], rgb[2]))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use super::*;
+
+    fn parse(content: &str) -> Result<MtlLibrary> {
+        parse_mtl(content, Path::new(""))
+    }
+
+    #[test]
+    fn parse_material_properties() {
+        let library = parse(
+            "# exported material\n\
+             newmtl Glass\n\
+             Ka 0.2 0.3 0.4\n\
+             Kd 0.5 0.6 0.7\n\
+             Ks 0.1 0.2 0.3\n\
+             Ns 96.4\n\
+             d 0.25\n\
+             Ni 1.5\n\
+             illum 2\n",
+        )
+        .unwrap();
+
+        let glass = library.materials["Glass"].to_material();
+        assert_eq!(glass.color, Color::new(0.5, 0.6, 0.7));
+        assert_eq!(glass.diffuse, 1.);
+        assert!((glass.ambient - 0.3).abs() < 1e-9);
+        assert!((glass.specular - 0.2).abs() < 1e-9);
+        assert_eq!(glass.shininess, 96);
+        assert_eq!(glass.transparency, 0.75);
+        assert_eq!(glass.refractive_index, 1.5);
+        assert_eq!(
+            library.warnings,
+            vec!["line 9: unsupported statement 'illum'"]
+        );
+    }
+
+    #[test]
+    fn unspecified_properties_keep_material_defaults() {
+        let library = parse("newmtl Plain\nnewmtl Clear\nTr 0.4\n").unwrap();
+
+        let plain = library.materials["Plain"].to_material();
+        let default = Material::default();
+        assert_eq!(plain.color, default.color);
+        assert_eq!(plain.diffuse, default.diffuse);
+        assert_eq!(plain.shininess, default.shininess);
+        assert_eq!(library.materials["Clear"].transparency, 0.4);
+    }
+
+    #[test]
+    fn malformed_lines_are_reported_with_line_numbers() {
+        let cases = [
+            ("Kd 1 1 1\n", "line 1: 'Kd' appears before any 'newmtl'"),
+            ("newmtl A\nKd 1 1\n", "line 2: expected 3 values, found 2"),
+            (
+                "newmtl A\n\nNs high\n",
+                "line 3: expected a number, found 'high'",
+            ),
+            ("newmtl A\nmap_Kd\n", "line 2: missing texture file name"),
+        ];
+        for (content, message) in cases {
+            let error = parse(content).err().unwrap();
+            assert_eq!(error.kind(), ErrorKind::InvalidData);
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn diffuse_texture_is_loaded_relative_to_library() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("red.ppm"), "P3\n1 1\n255\n255 0 0\n").unwrap();
+        fs::write(dir.path().join("lib.mtl"), "newmtl Red\nmap_Kd red.ppm\n").unwrap();
+
+        let library = read_mtl(&dir.path().join("lib.mtl").to_string_lossy()).unwrap();
+        let texture = library.materials["Red"].texture.as_ref().unwrap();
+        assert_eq!(texture.uv_color_at(0.5, 0.5), Color::new(1., 0., 0.));
+    }
+
+    #[test]
+    fn unreadable_texture_is_a_warning() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("photo.png"), b"\x89PNG\r\n").unwrap();
+
+        let library = parse_mtl(
+            "newmtl A\nmap_Kd -s 1 1 1 missing.ppm\nKd 1 0 0\nnewmtl B\nmap_Kd photo.png\n",
+            dir.path(),
+        )
+        .unwrap();
+        assert!(library.materials["A"].texture.is_none());
+        assert_eq!(library.materials["A"].color, Color::new(1., 0., 0.));
+        assert!(library.materials["B"].texture.is_none());
+        assert_eq!(library.warnings.len(), 2);
+        assert!(library.warnings[0].starts_with("line 2: cannot read texture"));
+        assert!(library.warnings[1].starts_with("line 5: cannot read texture"));
+    }
+}
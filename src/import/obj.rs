@@ -0,0 +1,660 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Result;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::drawing::Color;
+use crate::geometry::{Point, Vector};
+use crate::patterns::{Pattern, UvPattern};
+use crate::shapes::{Group, Material, Shape, SmoothTriangle, Transform, Triangle};
+
+use super::{line_error, parse_numbers, read_mtl, MtlMaterial};
+
+pub struct ObjFile {
+    pub vertices: Vec<Point>,
+    pub normals: Vec<Vector>,
+    pub texture_coords: Vec<(f64, f64)>,
+    pub materials: HashMap<String, MtlMaterial>,
+    pub warnings: Vec<String>,
+    default_group: Group,
+    objects: Vec<(String, Group)>,
+    groups: Vec<(Option<usize>, String, Group)>,
+    current_object: Option<usize>,
+    current_group: Option<usize>,
+    current_material: Option<String>,
+    base_dir: PathBuf,
+}
+
+pub fn read_obj(filename: &str) -> Result<ObjFile> {
+    let path = Path::new(filename);
+    parse_obj_in(
+        &fs::read_to_string(path)?,
+        path.parent().unwrap_or(Path::new("")),
+    )
+}
+
+pub fn parse_obj(content: &str) -> Result<ObjFile> {
+    parse_obj_in(content, Path::new(""))
+}
+
+fn parse_obj_in(content: &str, base_dir: &Path) -> Result<ObjFile> {
+    let mut obj = ObjFile {
+        vertices: vec![],
+        normals: vec![],
+        texture_coords: vec![],
+        materials: HashMap::new(),
+        warnings: vec![],
+        default_group: Group::new(),
+        objects: vec![],
+        groups: vec![],
+        current_object: None,
+        current_group: None,
+        current_material: None,
+        base_dir: base_dir.to_path_buf(),
+    };
+    for (index, line) in content.lines().enumerate() {
+        obj.parse_line(index + 1, line)?;
+    }
+    Ok(obj)
+}
+
+struct FaceVertex {
+    position: Point,
+    texture: Option<(f64, f64)>,
+    normal: Option<Vector>,
+}
+
+struct FaceTexture {
+    texture: Arc<dyn UvPattern>,
+    origin: Point,
+    e1: Vector,
+    e2: Vector,
+    uvs: [(f64, f64); 3],
+}
+
+impl ObjFile {
+    pub fn default_group(&self) -> &Group {
+        &self.default_group
+    }
+
+    pub fn object(&self, name: &str) -> Option<&Group> {
+        self.objects
+            .iter()
+            .find(|(object_name, _)| object_name == name)
+            .map(|(_, object)| object)
+    }
+
+    pub fn group(&self, name: &str) -> Option<&Group> {
+        self.groups
+            .iter()
+            .find(|(_, group_name, _)| group_name == name)
+            .map(|(_, _, group)| group)
+    }
+
+    pub fn into_group(self) -> Group {
+        let mut root = self.default_group;
+        let mut objects: Vec<Group> = self.objects.into_iter().map(|(_, group)| group).collect();
+        for (object, _, group) in self.groups {
+            if group.children().is_empty() {
+                continue;
+            }
+            match object {
+                Some(index) => objects[index].add_child(Box::new(group)),
+                None => root.add_child(Box::new(group)),
+            }
+        }
+        objects
+            .into_iter()
+            .filter(|object| !object.children().is_empty())
+            .fold(root, |root, object| root.with_child(Box::new(object)))
+    }
+
+    fn parse_line(&mut self, line_number: usize, line: &str) -> Result<()> {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut tokens = line.split_whitespace();
+        let Some(keyword) = tokens.next() else {
+            return Ok(());
+        };
+        let args: Vec<&str> = tokens.collect();
+
+        match keyword {
+            "v" => {
+                let xyz = parse_numbers(line_number, &args, 3)?;
+                self.vertices.push(Point::new(xyz[0], xyz[1], xyz[2]));
+            }
+            "vn" => {
+                let xyz = parse_numbers(line_number, &args, 3)?;
+                self.normals.push(Vector(xyz[0], xyz[1], xyz[2]));
+            }
+            "vt" => {
+                let uv = parse_numbers(line_number, &args, 1)?;
+                self.texture_coords
+                    .push((uv[0], uv.get(1).copied().unwrap_or_default()));
+            }
+            "f" => {
+                if args.len() < 3 {
+                    return Err(line_error(
+                        line_number,
+                        format!("a face needs at least 3 vertices, found {}", args.len()),
+                    ));
+                }
+                let vertices = args
+                    .iter()
+                    .map(|arg| self.face_vertex(line_number, arg))
+                    .collect::<Result<Vec<_>>>()?;
+                let material = self
+                    .current_material
+                    .as_ref()
+                    .and_then(|name| self.materials.get(name))
+                    .cloned()
+                    .unwrap_or_default();
+                if material.texture.is_some() && vertices.iter().any(|v| v.texture.is_none()) {
+                    self.warnings.push(format!(
+                        "line {line_number}: textured material '{}' used on a face without texture coordinates",
+                        self.current_material.as_deref().unwrap_or_default()
+                    ));
+                }
+                let triangles = fan_triangulation(&vertices, &material);
+                for _ in triangles.len()..vertices.len() - 2 {
+                    self.warnings
+                        .push(format!("line {line_number}: skipped degenerate triangle"));
+                }
+                for triangle in triangles {
+                    self.current_group().add_child(triangle);
+                }
+            }
+            "o" => self.select_object(args.join(" ")),
+            "g" => self.select_group(args.join(" ")),
+            "mtllib" => {
+                for filename in args {
+                    self.load_materials(line_number, filename)?;
+                }
+            }
+            "usemtl" => {
+                let name = args.join(" ");
+                if !self.materials.contains_key(&name) {
+                    self.warnings
+                        .push(format!("line {line_number}: unknown material '{name}'"));
+                }
+                self.current_material = Some(name);
+            }
+            _ => self.warnings.push(format!(
+                "line {line_number}: unsupported statement '{keyword}'"
+            )),
+        }
+        Ok(())
+    }
+
+    fn face_vertex(&self, line_number: usize, arg: &str) -> Result<FaceVertex> {
+        let mut parts = arg.split('/');
+        let position = parts.next().unwrap_or_default();
+        let texture = parts.next().filter(|s| !s.is_empty());
+        let normal = parts.next().filter(|s| !s.is_empty());
+
+        let position = self.vertices[resolve_index(line_number, position, self.vertices.len())?];
+        let texture = match texture {
+            Some(texture) => Some(
+                self.texture_coords
+                    [resolve_index(line_number, texture, self.texture_coords.len())?],
+            ),
+            None => None,
+        };
+        let normal = match normal {
+            Some(normal) => {
+                Some(self.normals[resolve_index(line_number, normal, self.normals.len())?])
+            }
+            None => None,
+        };
+        Ok(FaceVertex {
+            position,
+            texture,
+            normal,
+        })
+    }
+
+    fn load_materials(&mut self, line_number: usize, filename: &str) -> Result<()> {
+        let path = self.base_dir.join(filename);
+        let library = read_mtl(&path.to_string_lossy()).map_err(|err| {
+            line_error(
+                line_number,
+                format!("cannot read material library '{}': {err}", path.display()),
+            )
+        })?;
+        self.warnings.extend(
+            library
+                .warnings
+                .into_iter()
+                .map(|warning| format!("{}: {warning}", path.display())),
+        );
+        self.materials.extend(library.materials);
+        Ok(())
+    }
+
+    fn select_object(&mut self, name: String) {
+        let index = match self.objects.iter().position(|(n, _)| *n == name) {
+            Some(index) => index,
+            None => {
+                self.objects.push((name, Group::new()));
+                self.objects.len() - 1
+            }
+        };
+        self.current_object = Some(index);
+        self.current_group = None;
+    }
+
+    fn select_group(&mut self, name: String) {
+        let object = self.current_object;
+        let index = match self
+            .groups
+            .iter()
+            .position(|(o, n, _)| *o == object && *n == name)
+        {
+            Some(index) => index,
+            None => {
+                self.groups.push((object, name, Group::new()));
+                self.groups.len() - 1
+            }
+        };
+        self.current_group = Some(index);
+    }
+
+    fn current_group(&mut self) -> &mut Group {
+        match (self.current_group, self.current_object) {
+            (Some(index), _) => &mut self.groups[index].2,
+            (None, Some(index)) => &mut self.objects[index].1,
+            (None, None) => &mut self.default_group,
+        }
+    }
+}
+
+fn resolve_index(line_number: usize, token: &str, count: usize) -> Result<usize> {
+    let invalid = || line_error(line_number, format!("invalid index '{token}'"));
+    let index: i64 = token.parse().map_err(|_| invalid())?;
+    let resolved = if index < 0 {
+        count as i64 + index
+    } else {
+        index - 1
+    };
+    if !(0..count as i64).contains(&resolved) {
+        return Err(invalid());
+    }
+    Ok(resolved as usize)
+}
+
+fn fan_triangulation(vertices: &[FaceVertex], material: &MtlMaterial) -> Vec<Box<dyn Shape>> {
+    let first = &vertices[0];
+    vertices[1..]
+        .windows(2)
+        .filter(|pair| {
+            !Triangle::is_degenerate([first.position, pair[0].position, pair[1].position])
+        })
+        .map(|pair| -> Box<dyn Shape> {
+            let (second, third) = (&pair[0], &pair[1]);
+            let material = face_material(material, [first, second, third]);
+            match (first.normal, second.normal, third.normal) {
+                (Some(n1), Some(n2), Some(n3)) => Box::new(
+                    SmoothTriangle::new(
+                        first.position,
+                        second.position,
+                        third.position,
+                        n1,
+                        n2,
+                        n3,
+                    )
+                    .with_material(material),
+                ),
+                _ => Box::new(
+                    Triangle::new(first.position, second.position, third.position)
+                        .with_material(material),
+                ),
+            }
+        })
+        .collect()
+}
+
+fn face_material(material: &MtlMaterial, vertices: [&FaceVertex; 3]) -> Material {
+    let mut result = material.to_material();
+    if let (Some(texture), [Some(t1), Some(t2), Some(t3)]) =
+        (&material.texture, vertices.map(|vertex| vertex.texture))
+    {
+        result.pattern = Some(Box::new(FaceTexture {
+            texture: texture.clone(),
+            origin: vertices[0].position,
+            e1: vertices[1].position - vertices[0].position,
+            e2: vertices[2].position - vertices[0].position,
+            uvs: [t1, t2, t3],
+        }));
+    }
+    result
+}
+
+impl Pattern for FaceTexture {
+    fn transform(&self) -> &Transform {
+        &Transform::IDENTITY
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let to_point = *pt - self.origin;
+        let (d11, d12, d22) = (
+            self.e1.dot(&self.e1),
+            self.e1.dot(&self.e2),
+            self.e2.dot(&self.e2),
+        );
+        let (p1, p2) = (to_point.dot(&self.e1), to_point.dot(&self.e2));
+        let denominator = d11 * d22 - d12 * d12;
+        let b = (d22 * p1 - d12 * p2) / denominator;
+        let c = (d11 * p2 - d12 * p1) / denominator;
+
+        let [(u1, v1), (u2, v2), (u3, v3)] = self.uvs;
+        self.texture.uv_color_at(
+            u1 + (u2 - u1) * b + (u3 - u1) * c,
+            v1 + (v2 - v1) * b + (v3 - v1) * c,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::geometry::{Aabb, Normal, Ray, UnitVector};
+
+    use super::*;
+
+    #[test]
+    fn ignore_unsupported_statements_with_warnings() {
+        let obj = parse_obj(
+            "There was a young lady named Bright\n\
+             who traveled much faster than light.\n\
+             \n\
+             s off\n",
+        )
+        .unwrap();
+
+        assert!(obj.vertices.is_empty());
+        assert_eq!(obj.warnings.len(), 3);
+        assert_eq!(obj.warnings[2], "line 4: unsupported statement 's'");
+    }
+
+    #[test]
+    fn parse_vertex_data() {
+        let obj = parse_obj(
+            "v -1 1 0\n\
+             v -1.0000 0.5000 0.0000 # comment\n\
+             vn 0 0 1\n\
+             vt 0.25 0.75\n\
+             vt 0.5\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            obj.vertices,
+            vec![Point::new(-1., 1., 0.), Point::new(-1., 0.5, 0.)]
+        );
+        assert_eq!(obj.normals, vec![Vector(0., 0., 1.)]);
+        assert_eq!(obj.texture_coords, vec![(0.25, 0.75), (0.5, 0.)]);
+        assert!(obj.warnings.is_empty());
+    }
+
+    #[test]
+    fn polygons_are_fan_triangulated() {
+        let obj = parse_obj(
+            "v -1 1 0\n\
+             v -1 0 0\n\
+             v 1 0 0\n\
+             v 1 1 0\n\
+             v 0 2 0\n\
+             f 1 2 3\n\
+             f 1 2 3 4 5\n",
+        )
+        .unwrap();
+
+        let group = obj.default_group();
+        assert_eq!(group.children().len(), 4);
+        assert_eq!(
+            group.bounds(),
+            Aabb::new(Point::new(-1., 0., 0.), Point::new(1., 2., 0.))
+        );
+    }
+
+    #[test]
+    fn degenerate_triangles_are_skipped_with_warnings() {
+        let obj = parse_obj(
+            "v 0 0 0\n\
+             v 1 0 0\n\
+             v 2 0 0\n\
+             v 0 1 0\n\
+             f 1 2 3\n\
+             f 1 2 3 4\n",
+        )
+        .unwrap();
+
+        assert_eq!(obj.default_group().children().len(), 1);
+        assert_eq!(
+            obj.warnings,
+            vec![
+                "line 5: skipped degenerate triangle",
+                "line 6: skipped degenerate triangle"
+            ]
+        );
+    }
+
+    #[test]
+    fn faces_with_normals_become_smooth_triangles() {
+        let obj = parse_obj(
+            "v 0 1 0\n\
+             v -1 0 0\n\
+             v 1 0 0\n\
+             vt 0 0\n\
+             vn 0 0 -1\n\
+             vn -1 0 -1\n\
+             vn 1 0 -1\n\
+             f 1//1 2//2 3//3\n\
+             f 1/1/1 2/1/2 3/1/3\n",
+        )
+        .unwrap();
+
+        let group = obj.into_group();
+        let ray = Ray::new(Point::new(-0.2, 0.3, -2.), UnitVector::Z);
+        let xs = group.intersect_with(&ray);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(
+            xs[0].normal_at(&Point::new(-0.2, 0.3, 0.)),
+            Normal::from(&Vector(-0.2, 0., -1.))
+        );
+    }
+
+    #[test]
+    fn negative_indices_are_relative_to_the_end() {
+        let obj = parse_obj(
+            "v 0 1 0\n\
+             v -1 0 0\n\
+             v 1 0 0\n\
+             f -3 -2 -1\n",
+        )
+        .unwrap();
+
+        assert_eq!(obj.default_group().children().len(), 1);
+    }
+
+    #[test]
+    fn named_groups_are_nested_in_the_default_group() {
+        let obj = parse_obj(
+            "v -1 1 0\n\
+             v -1 0 0\n\
+             v 1 0 0\n\
+             v 1 1 0\n\
+             g FirstGroup\n\
+             f 1 2 3\n\
+             g SecondGroup\n\
+             f 1 3 4\n\
+             g FirstGroup\n\
+             f 1 2 4\n",
+        )
+        .unwrap();
+
+        assert_eq!(obj.group("FirstGroup").unwrap().children().len(), 2);
+        assert_eq!(obj.group("SecondGroup").unwrap().children().len(), 1);
+        assert!(obj.group("ThirdGroup").is_none());
+
+        let root = obj.into_group();
+        assert_eq!(root.children().len(), 2);
+    }
+
+    #[test]
+    fn objects_contain_the_groups_that_follow_them() {
+        let obj = parse_obj(
+            "v 0 1 0\n\
+             v -1 0 0\n\
+             v 1 0 0\n\
+             o Teapot\n\
+             f 1 2 3\n\
+             g Lid\n\
+             f 1 2 3\n\
+             g Body\n\
+             f 1 2 3\n\
+             o Cup\n\
+             g Lid\n\
+             f 1 2 3\n\
+             g Lid\n\
+             f 1 2 3\n",
+        )
+        .unwrap();
+
+        assert_eq!(obj.object("Teapot").unwrap().children().len(), 1);
+        assert!(obj.object("Cup").unwrap().children().is_empty());
+        assert_eq!(obj.group("Lid").unwrap().children().len(), 1);
+
+        let root = obj.into_group();
+        assert_eq!(root.children().len(), 2);
+        let ray = Ray::new(Point::new(0., 0.5, -2.), UnitVector::Z);
+        let mut depths: Vec<usize> = root
+            .intersect_with(&ray)
+            .iter()
+            .map(|x| x.parents.len())
+            .collect();
+        depths.sort();
+        assert_eq!(depths, vec![2, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn malformed_lines_are_reported_with_line_numbers() {
+        let cases = [
+            ("v 1 2\n", "line 1: expected 3 values, found 2"),
+            (
+                "v 1 2 3\nvn 1 x 0\n",
+                "line 2: expected a number, found 'x'",
+            ),
+            (
+                "v 1 2 3\n\nf 1 1\n",
+                "line 3: a face needs at least 3 vertices, found 2",
+            ),
+            ("v 1 2 3\nf 1 1 2\n", "line 2: invalid index '2'"),
+            ("v 1 2 3\nf 1 1 0\n", "line 2: invalid index '0'"),
+            ("v 1 2 3\nf 1 1 1//1\n", "line 2: invalid index '1'"),
+        ];
+        for (content, message) in cases {
+            let error = parse_obj(content).err().unwrap();
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn read_obj_from_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, "v 0 1 0\nv -1 0 0\nv 1 0 0\nf 1 2 3\n").unwrap();
+
+        let obj = read_obj(file.path().to_str().unwrap()).unwrap();
+        assert_eq!(obj.default_group().children().len(), 1);
+    }
+
+    mod materials {
+        use std::fs;
+
+        use crate::drawing::Color;
+
+        use super::*;
+
+        fn write_scene(dir: &Path) {
+            fs::write(dir.join("checker.ppm"), "P3\n2 1\n255\n255 0 0  0 0 255\n").unwrap();
+            fs::write(
+                dir.join("scene.mtl"),
+                "newmtl Red\nKd 1 0 0\nnewmtl Textured\nmap_Kd checker.ppm\n",
+            )
+            .unwrap();
+            fs::write(
+                dir.join("scene.obj"),
+                "mtllib scene.mtl\n\
+                 v 0 0 0\n\
+                 v 1 0 0\n\
+                 v 0 1 0\n\
+                 vt 0 0\n\
+                 vt 1 0\n\
+                 vt 0 1\n\
+                 f 1 2 3\n\
+                 usemtl Red\n\
+                 f 1 2 3\n\
+                 usemtl Textured\n\
+                 f 1/1 2/2 3/3\n\
+                 usemtl Missing\n\
+                 f 1 2 3\n\
+                 usemtl Textured\n\
+                 f 1 2 3\n",
+            )
+            .unwrap();
+        }
+
+        fn hit_color(group: &Group, index: usize, pt: Point) -> Color {
+            group.children()[index].material().color_at(&pt)
+        }
+
+        #[test]
+        fn usemtl_assigns_materials_to_following_faces() {
+            let dir = tempfile::tempdir().unwrap();
+            write_scene(dir.path());
+
+            let obj = read_obj(&dir.path().join("scene.obj").to_string_lossy()).unwrap();
+            assert_eq!(obj.materials.len(), 2);
+            assert_eq!(
+                obj.warnings,
+                vec![
+                    "line 13: unknown material 'Missing'",
+                    "line 16: textured material 'Textured' used on a face without texture coordinates"
+                ]
+            );
+
+            let group = obj.default_group();
+            let point = Point::new(0.25, 0.25, 0.);
+            assert_eq!(hit_color(group, 0, point), Color::WHITE);
+            assert_eq!(hit_color(group, 1, point), Color::new(1., 0., 0.));
+            assert_eq!(hit_color(group, 3, point), Color::WHITE);
+        }
+
+        #[test]
+        fn texture_coordinates_are_interpolated_across_faces() {
+            let dir = tempfile::tempdir().unwrap();
+            write_scene(dir.path());
+
+            let obj = read_obj(&dir.path().join("scene.obj").to_string_lossy()).unwrap();
+            let group = obj.default_group();
+            assert_eq!(
+                hit_color(group, 2, Point::new(0.25, 0.25, 0.)),
+                Color::new(1., 0., 0.)
+            );
+            assert_eq!(
+                hit_color(group, 2, Point::new(0.75, 0.1, 0.)),
+                Color::new(0., 0., 1.)
+            );
+        }
+
+        #[test]
+        fn missing_library_is_reported_with_line_number() {
+            let error = parse_obj("v 0 0 0\nmtllib missing.mtl\n").err().unwrap();
+            assert!(error
+                .to_string()
+                .starts_with("line 2: cannot read material library 'missing.mtl'"));
+        }
+    }
+}
@@ -0,0 +1,573 @@
+use std::fs;
+use std::io::Result;
+
+use crate::drawing::Color;
+use crate::geometry::{Point, Vector};
+use crate::shapes::{Group, Material, Mesh, Shape, SmoothTriangle, Triangle};
+
+use super::{invalid_data, line_error, Bytes, Tokens};
+
+pub struct PlyFile {
+    pub warnings: Vec<String>,
+    vertices: Vec<PlyVertex>,
+    faces: Vec<[usize; 3]>,
+}
+
+pub fn read_ply(filename: &str) -> Result<PlyFile> {
+    parse_ply(&fs::read(filename)?)
+}
+
+pub fn parse_ply(content: &[u8]) -> Result<PlyFile> {
+    let (header, body) = split_header(content)?;
+    let header = Header::parse(header)?;
+    let mut body = match header.format.as_str() {
+        "ascii" => {
+            let text = std::str::from_utf8(body)
+                .map_err(|_| invalid_data("ASCII PLY body is not valid text".to_string()))?;
+            Body::Ascii(Tokens::new(text, header.line_count + 1))
+        }
+        "binary_little_endian" => Body::Binary(Bytes::new(body)),
+        format => return Err(invalid_data(format!("unsupported PLY format '{format}'"))),
+    };
+
+    let mut ply = PlyFile {
+        warnings: vec![],
+        vertices: vec![],
+        faces: vec![],
+    };
+    for element in &header.elements {
+        match element.name.as_str() {
+            "vertex" => ply.vertices = element.read_vertices(&mut body)?,
+            "face" => element.read_faces(&mut body, &mut ply)?,
+            _ => {
+                element.read_records(&mut body)?;
+            }
+        }
+    }
+    Ok(ply)
+}
+
+impl PlyFile {
+    pub fn face_count(&self) -> usize {
+        self.faces.len()
+    }
+
+    pub fn into_group(self) -> Group {
+        self.faces.iter().fold(Group::new(), |group, face| {
+            group.with_child(triangle(face.map(|index| &self.vertices[index])))
+        })
+    }
+
+    pub fn into_mesh(self) -> Mesh {
+        let normals: Option<Vec<Vector>> = self.vertices.iter().map(|v| v.normal).collect();
+        let positions = self.vertices.iter().map(|v| v.position).collect();
+        let mesh = Mesh::new(positions, self.faces);
+        match normals {
+            Some(normals) => mesh.with_normals(normals),
+            None => mesh,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScalarType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl ScalarType {
+    fn parse(name: &str) -> Option<ScalarType> {
+        match name {
+            "char" | "int8" => Some(ScalarType::I8),
+            "uchar" | "uint8" => Some(ScalarType::U8),
+            "short" | "int16" => Some(ScalarType::I16),
+            "ushort" | "uint16" => Some(ScalarType::U16),
+            "int" | "int32" => Some(ScalarType::I32),
+            "uint" | "uint32" => Some(ScalarType::U32),
+            "float" | "float32" => Some(ScalarType::F32),
+            "double" | "float64" => Some(ScalarType::F64),
+            _ => None,
+        }
+    }
+
+    fn is_integer(&self) -> bool {
+        !matches!(self, ScalarType::F32 | ScalarType::F64)
+    }
+
+    fn read(&self, bytes: &mut Bytes) -> Result<f64> {
+        Ok(match self {
+            ScalarType::I8 => i8::from_le_bytes(bytes.take()?) as f64,
+            ScalarType::U8 => u8::from_le_bytes(bytes.take()?) as f64,
+            ScalarType::I16 => i16::from_le_bytes(bytes.take()?) as f64,
+            ScalarType::U16 => u16::from_le_bytes(bytes.take()?) as f64,
+            ScalarType::I32 => i32::from_le_bytes(bytes.take()?) as f64,
+            ScalarType::U32 => bytes.u32()? as f64,
+            ScalarType::F32 => bytes.f32()?,
+            ScalarType::F64 => f64::from_le_bytes(bytes.take()?),
+        })
+    }
+}
+
+enum PropertyKind {
+    Scalar(ScalarType),
+    List(ScalarType, ScalarType),
+}
+
+struct Property {
+    name: String,
+    kind: PropertyKind,
+}
+
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+struct Header {
+    format: String,
+    elements: Vec<Element>,
+    line_count: usize,
+}
+
+enum Body<'a> {
+    Ascii(Tokens<'a>),
+    Binary(Bytes<'a>),
+}
+
+impl Body<'_> {
+    fn read(&mut self, scalar_type: ScalarType) -> Result<f64> {
+        match self {
+            Body::Ascii(tokens) => tokens.number(),
+            Body::Binary(bytes) => scalar_type.read(bytes),
+        }
+    }
+}
+
+fn split_header(content: &[u8]) -> Result<(&str, &[u8])> {
+    const END_HEADER: &[u8] = b"end_header";
+    let end = content
+        .windows(END_HEADER.len())
+        .position(|window| window == END_HEADER)
+        .ok_or_else(|| invalid_data("PLY header is missing 'end_header'".to_string()))?;
+    let body_start = content[end..]
+        .iter()
+        .position(|&b| b == b'\n')
+        .map_or(content.len(), |newline| end + newline + 1);
+    let header = std::str::from_utf8(&content[..end])
+        .map_err(|_| invalid_data("PLY header is not valid text".to_string()))?;
+    Ok((header, &content[body_start..]))
+}
+
+impl Header {
+    fn parse(text: &str) -> Result<Header> {
+        let mut header = Header {
+            format: String::new(),
+            elements: vec![],
+            line_count: text.lines().count() + 1,
+        };
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                ["ply"] if line_number == 1 => {}
+                _ if line_number == 1 => {
+                    return Err(line_error(1, "not a PLY file".to_string()));
+                }
+                [] | ["comment" | "obj_info", ..] => {}
+                ["format", format, _version] => header.format = format.to_string(),
+                ["element", name, count] => {
+                    let count = count.parse().map_err(|_| {
+                        line_error(line_number, format!("invalid element count '{count}'"))
+                    })?;
+                    header.elements.push(Element {
+                        name: name.to_string(),
+                        count,
+                        properties: vec![],
+                    });
+                }
+                ["property", "list", count_type, item_type, name] => {
+                    let kind = PropertyKind::List(
+                        scalar_type(line_number, count_type)?,
+                        scalar_type(line_number, item_type)?,
+                    );
+                    header.add_property(line_number, name, kind)?;
+                }
+                ["property", value_type, name] => {
+                    let kind = PropertyKind::Scalar(scalar_type(line_number, value_type)?);
+                    header.add_property(line_number, name, kind)?;
+                }
+                _ => {
+                    return Err(line_error(
+                        line_number,
+                        format!("malformed header line '{line}'"),
+                    ))
+                }
+            }
+        }
+        if header.format.is_empty() {
+            return Err(invalid_data("PLY header is missing 'format'".to_string()));
+        }
+        Ok(header)
+    }
+
+    fn add_property(&mut self, line_number: usize, name: &str, kind: PropertyKind) -> Result<()> {
+        let element = self.elements.last_mut().ok_or_else(|| {
+            line_error(
+                line_number,
+                "property appears before any element".to_string(),
+            )
+        })?;
+        element.properties.push(Property {
+            name: name.to_string(),
+            kind,
+        });
+        Ok(())
+    }
+}
+
+fn scalar_type(line_number: usize, name: &str) -> Result<ScalarType> {
+    ScalarType::parse(name)
+        .ok_or_else(|| line_error(line_number, format!("unknown property type '{name}'")))
+}
+
+impl Element {
+    fn read_records(&self, body: &mut Body) -> Result<Vec<Vec<Vec<f64>>>> {
+        (0..self.count)
+            .map(|_| {
+                self.properties
+                    .iter()
+                    .map(|property| match property.kind {
+                        PropertyKind::Scalar(value_type) => Ok(vec![body.read(value_type)?]),
+                        PropertyKind::List(count_type, item_type) => {
+                            let count = body.read(count_type)? as usize;
+                            (0..count).map(|_| body.read(item_type)).collect()
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn property_index(&self, name: &str) -> Option<usize> {
+        self.properties.iter().position(|p| p.name == name)
+    }
+
+    fn scalar_index(&self, name: &str) -> Result<Option<usize>> {
+        match self.property_index(name) {
+            Some(index) if matches!(self.properties[index].kind, PropertyKind::List(..)) => Err(
+                invalid_data(format!("vertex property '{name}' must not be a list")),
+            ),
+            index => Ok(index),
+        }
+    }
+
+    fn read_vertices(&self, body: &mut Body) -> Result<Vec<PlyVertex>> {
+        let required = |name: &str| {
+            self.scalar_index(name)?
+                .ok_or_else(|| invalid_data(format!("vertex element has no '{name}' property")))
+        };
+        let optional = |names: [&str; 3]| -> Result<[Option<usize>; 3]> {
+            Ok([
+                self.scalar_index(names[0])?,
+                self.scalar_index(names[1])?,
+                self.scalar_index(names[2])?,
+            ])
+        };
+        let position = [required("x")?, required("y")?, required("z")?];
+        let normal = optional(["nx", "ny", "nz"])?;
+        let color = optional(["red", "green", "blue"])?;
+        let color_scale = match color[0].map(|index| &self.properties[index].kind) {
+            Some(PropertyKind::Scalar(value_type)) if value_type.is_integer() => 255.,
+            _ => 1.,
+        };
+
+        let records = self.read_records(body)?;
+        Ok(records
+            .iter()
+            .map(|record| {
+                let value = |index: usize| record[index][0];
+                PlyVertex {
+                    position: Point::new(
+                        value(position[0]),
+                        value(position[1]),
+                        value(position[2]),
+                    ),
+                    normal: match normal {
+                        [Some(x), Some(y), Some(z)] => Some(Vector(value(x), value(y), value(z))),
+                        _ => None,
+                    },
+                    color: match color {
+                        [Some(r), Some(g), Some(b)] => Some(Color::new(
+                            value(r) / color_scale,
+                            value(g) / color_scale,
+                            value(b) / color_scale,
+                        )),
+                        _ => None,
+                    },
+                }
+            })
+            .collect())
+    }
+
+    fn read_faces(&self, body: &mut Body, ply: &mut PlyFile) -> Result<()> {
+        let indices = self
+            .property_index("vertex_indices")
+            .or_else(|| self.property_index("vertex_index"))
+            .ok_or_else(|| invalid_data("face element has no 'vertex_indices' list".to_string()))?;
+
+        for (face, record) in self.read_records(body)?.iter().enumerate() {
+            let face_vertices = record[indices]
+                .iter()
+                .map(|&index| {
+                    Some(index)
+                        .filter(|index| index.fract() == 0. && *index >= 0.)
+                        .map(|index| index as usize)
+                        .filter(|&index| index < ply.vertices.len())
+                        .ok_or_else(|| {
+                            invalid_data(format!("face {face} references missing vertex {index}"))
+                        })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if face_vertices.len() < 3 {
+                return Err(invalid_data(format!(
+                    "face {face} has {} vertices, at least 3 are needed",
+                    face_vertices.len()
+                )));
+            }
+            for pair in face_vertices[1..].windows(2) {
+                let indices = [face_vertices[0], pair[0], pair[1]];
+                if Triangle::is_degenerate(indices.map(|index| ply.vertices[index].position)) {
+                    ply.warnings
+                        .push(format!("face {face}: skipped degenerate triangle"));
+                } else {
+                    ply.faces.push(indices);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct PlyVertex {
+    position: Point,
+    normal: Option<Vector>,
+    color: Option<Color>,
+}
+
+fn triangle(vertices: [&PlyVertex; 3]) -> Box<dyn Shape> {
+    let [v1, v2, v3] = vertices;
+    let material = match (&v1.color, &v2.color, &v3.color) {
+        (Some(c1), Some(c2), Some(c3)) => Material::default_with_color((c1 + c2 + c3) * (1. / 3.)),
+        _ => Material::default(),
+    };
+    match (v1.normal, v2.normal, v3.normal) {
+        (Some(n1), Some(n2), Some(n3)) => Box::new(
+            SmoothTriangle::new(v1.position, v2.position, v3.position, n1, n2, n3)
+                .with_material(material),
+        ),
+        _ => Box::new(Triangle::new(v1.position, v2.position, v3.position).with_material(material)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Aabb, Normal, Ray, UnitVector};
+
+    use super::*;
+
+    const ASCII_SQUARE: &str = "ply\n\
+        format ascii 1.0\n\
+        comment a unit square\n\
+        element vertex 4\n\
+        property float x\n\
+        property float y\n\
+        property float z\n\
+        property uchar red\n\
+        property uchar green\n\
+        property uchar blue\n\
+        element face 1\n\
+        property list uchar int vertex_indices\n\
+        end_header\n\
+        0 0 0 255 0 0\n\
+        1 0 0 255 0 0\n\
+        1 1 0 255 0 0\n\
+        0 1 0 255 0 0\n\
+        4 0 1 2 3\n";
+
+    fn binary_triangle(truncate: usize) -> Vec<u8> {
+        let mut content = b"ply\n\
+            format binary_little_endian 1.0\n\
+            element vertex 3\n\
+            property float x\n\
+            property float y\n\
+            property float z\n\
+            property float nx\n\
+            property float ny\n\
+            property float nz\n\
+            element face 1\n\
+            property list uchar uint vertex_index\n\
+            end_header\n"
+            .to_vec();
+        for (position, normal) in [
+            ([0f32, 1., 0.], [0f32, 0., -1.]),
+            ([-1., 0., 0.], [-1., 0., -1.]),
+            ([1., 0., 0.], [1., 0., -1.]),
+        ] {
+            for value in position.iter().chain(normal.iter()) {
+                content.extend(value.to_le_bytes());
+            }
+        }
+        content.push(3);
+        for index in [0u32, 1, 2] {
+            content.extend(index.to_le_bytes());
+        }
+        content.truncate(content.len() - truncate);
+        content
+    }
+
+    #[test]
+    fn read_ascii_ply_with_colors() {
+        let group = parse_ply(ASCII_SQUARE.as_bytes()).unwrap().into_group();
+
+        assert_eq!(group.children().len(), 2);
+        assert_eq!(
+            group.bounds(),
+            Aabb::new(Point::new(0., 0., 0.), Point::new(1., 1., 0.))
+        );
+        assert_eq!(
+            group.children()[0].material().color_at(&Point::ZERO),
+            Color::new(1., 0., 0.)
+        );
+    }
+
+    #[test]
+    fn read_binary_ply_with_normals() {
+        let group = parse_ply(&binary_triangle(0)).unwrap().into_group();
+
+        let ray = Ray::new(Point::new(-0.2, 0.3, -2.), UnitVector::Z);
+        let xs = group.intersect_with(&ray);
+        assert_eq!(xs.len(), 1);
+        assert_eq!(
+            xs[0].normal_at(&Point::new(-0.2, 0.3, 0.)),
+            Normal::from(&Vector(-0.2, 0., -1.))
+        );
+    }
+
+    #[test]
+    fn read_ply_as_mesh() {
+        let mesh = parse_ply(ASCII_SQUARE.as_bytes()).unwrap().into_mesh();
+        assert_eq!(mesh.face_count(), 2);
+
+        let mesh = parse_ply(&binary_triangle(0)).unwrap().into_mesh();
+        let ray = Ray::new(Point::new(-0.2, 0.3, -2.), UnitVector::Z);
+        let xs = mesh.intersect_with(&ray);
+        assert_eq!(
+            xs[0].normal_at(&Point::new(-0.2, 0.3, 0.)),
+            Normal::from(&Vector(-0.2, 0., -1.))
+        );
+    }
+
+    #[test]
+    fn unknown_elements_are_skipped() {
+        let content = "ply\n\
+            format ascii 1.0\n\
+            element material 1\n\
+            property list uchar float values\n\
+            element vertex 3\n\
+            property double x\n\
+            property double y\n\
+            property double z\n\
+            element face 1\n\
+            property list uchar int vertex_indices\n\
+            end_header\n\
+            2 0.5 0.5\n\
+            0 1 0\n-1 0 0\n1 0 0\n\
+            3 0 1 2\n";
+
+        assert_eq!(parse_ply(content.as_bytes()).unwrap().face_count(), 1);
+    }
+
+    #[test]
+    fn degenerate_triangles_are_skipped_with_warnings() {
+        let content = ASCII_SQUARE.replace("1 1 0 255", "2 0 0 255");
+        let ply = parse_ply(content.as_bytes()).unwrap();
+
+        assert_eq!(ply.face_count(), 1);
+        assert_eq!(ply.warnings, vec!["face 0: skipped degenerate triangle"]);
+    }
+
+    #[test]
+    fn truncated_files_are_rejected() {
+        let ascii = &ASCII_SQUARE[..ASCII_SQUARE.len() - 4];
+        let error = parse_ply(ascii.as_bytes()).err().unwrap();
+        assert_eq!(error.to_string(), "line 18: unexpected end of file");
+
+        let error = parse_ply(&binary_triangle(2)).err().unwrap();
+        assert!(error.to_string().starts_with("unexpected end of data"));
+    }
+
+    #[test]
+    fn malformed_files_are_rejected() {
+        let cases = [
+            ("solid\nend_header\n", "line 1: not a PLY file"),
+            (
+                "ply\nformat ascii 1.0\n",
+                "PLY header is missing 'end_header'",
+            ),
+            (
+                "ply\nformat ascii 1.0\nproperty float x\nend_header\n",
+                "line 3: property appears before any element",
+            ),
+            (
+                "ply\nformat ascii 1.0\nelement vertex 1\nproperty half x\nend_header\n",
+                "line 4: unknown property type 'half'",
+            ),
+            (
+                "ply\nformat binary_big_endian 1.0\nend_header\n",
+                "unsupported PLY format 'binary_big_endian'",
+            ),
+            (
+                "ply\nformat ascii 1.0\nelement vertex 1\nproperty float x\nend_header\n0\n",
+                "vertex element has no 'y' property",
+            ),
+            (
+                "ply\nformat ascii 1.0\n\
+                 element vertex 1\nproperty float x\nproperty float y\nproperty float z\n\
+                 element face 1\nproperty list uchar int vertex_indices\n\
+                 end_header\n0 0 0\n3 0 0 1\n",
+                "face 0 references missing vertex 1",
+            ),
+            (
+                "ply\nformat ascii 1.0\n\
+                 element vertex 3\nproperty float x\nproperty float y\nproperty float z\n\
+                 element face 1\nproperty list uchar float vertex_indices\n\
+                 end_header\n0 0 0\n1 0 0\n0 1 0\n3 -1 1.9 2\n",
+                "face 0 references missing vertex -1",
+            ),
+            (
+                "ply\nformat ascii 1.0\n\
+                 element vertex 3\nproperty float x\nproperty float y\nproperty float z\n\
+                 element face 1\nproperty list uchar float vertex_indices\n\
+                 end_header\n0 0 0\n1 0 0\n0 1 0\n3 0 1.9 2\n",
+                "face 0 references missing vertex 1.9",
+            ),
+            (
+                "ply\nformat ascii 1.0\n\
+                 element vertex 1\nproperty list uchar float x\nproperty float y\nproperty float z\n\
+                 end_header\n0 0 0\n",
+                "vertex property 'x' must not be a list",
+            ),
+        ];
+        for (content, message) in cases {
+            let error = parse_ply(content.as_bytes()).err().unwrap();
+            assert_eq!(error.to_string(), message);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::fs;
+use std::io::Result;
+
+use crate::geometry::Point;
+use crate::shapes::{Group, Mesh, Triangle};
+
+use super::{invalid_data, Bytes, Tokens};
+
+pub struct StlFile {
+    pub warnings: Vec<String>,
+    triangles: Vec<[Point; 3]>,
+}
+
+pub fn read_stl(filename: &str) -> Result<StlFile> {
+    parse_stl(&fs::read(filename)?)
+}
+
+pub fn parse_stl(content: &[u8]) -> Result<StlFile> {
+    const HEADER_SIZE: usize = 80;
+    const FACET_SIZE: usize = 50;
+
+    let binary_size = content
+        .get(HEADER_SIZE..HEADER_SIZE + 4)
+        .map(|count| u32::from_le_bytes(count.try_into().unwrap()) as usize)
+        .map(|count| HEADER_SIZE + 4 + count * FACET_SIZE);
+    let facets = if binary_size != Some(content.len()) && content.starts_with(b"solid") {
+        let text = std::str::from_utf8(content)
+            .map_err(|_| invalid_data("ASCII STL is not valid text".to_string()))?;
+        read_ascii(&mut Tokens::new(text, 1))?
+    } else {
+        read_binary(&mut Bytes::new(
+            content.get(HEADER_SIZE..).unwrap_or_default(),
+        ))?
+    };
+
+    let mut stl = StlFile {
+        warnings: vec![],
+        triangles: vec![],
+    };
+    for (facet, vertices) in facets.into_iter().enumerate() {
+        if Triangle::is_degenerate(vertices) {
+            stl.warnings
+                .push(format!("facet {facet}: skipped degenerate triangle"));
+        } else {
+            stl.triangles.push(vertices);
+        }
+    }
+    Ok(stl)
+}
+
+impl StlFile {
+    pub fn face_count(&self) -> usize {
+        self.triangles.len()
+    }
+
+    pub fn into_group(self) -> Group {
+        self.triangles
+            .into_iter()
+            .fold(Group::new(), |group, [p1, p2, p3]| {
+                group.with_child(Box::new(Triangle::new(p1, p2, p3)))
+            })
+    }
+
+    pub fn into_mesh(self) -> Mesh {
+        let faces = (0..self.triangles.len())
+            .map(|face| [3 * face, 3 * face + 1, 3 * face + 2])
+            .collect();
+        Mesh::new(self.triangles.into_iter().flatten().collect(), faces)
+    }
+}
+
+fn read_ascii(tokens: &mut Tokens) -> Result<Vec<[Point; 3]>> {
+    tokens.expect("solid")?;
+    while tokens
+        .peek()
+        .is_some_and(|token| token != "facet" && token != "endsolid")
+    {
+        tokens.next()?;
+    }
+
+    let mut triangles = vec![];
+    while tokens.peek() == Some("facet") {
+        tokens.next()?;
+        tokens.expect("normal")?;
+        for _ in 0..3 {
+            tokens.number()?;
+        }
+        tokens.expect("outer")?;
+        tokens.expect("loop")?;
+        let mut vertices = [Point::ZERO; 3];
+        for vertex in &mut vertices {
+            tokens.expect("vertex")?;
+            *vertex = Point::new(tokens.number()?, tokens.number()?, tokens.number()?);
+        }
+        tokens.expect("endloop")?;
+        tokens.expect("endfacet")?;
+        triangles.push(vertices);
+    }
+    tokens.expect("endsolid")?;
+    Ok(triangles)
+}
+
+fn read_binary(bytes: &mut Bytes) -> Result<Vec<[Point; 3]>> {
+    let count = bytes.u32()?;
+    (0..count)
+        .map(|_| {
+            for _ in 0..3 {
+                bytes.f32()?;
+            }
+            let mut vertices = [Point::ZERO; 3];
+            for vertex in &mut vertices {
+                *vertex = Point::new(bytes.f32()?, bytes.f32()?, bytes.f32()?);
+            }
+            bytes.take::<2>()?;
+            Ok(vertices)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Aabb, Normal, Ray, UnitVector};
+    use crate::shapes::Shape;
+
+    use super::*;
+
+    const ASCII_TRIANGLE: &str = "solid triangle\n\
+        facet normal 0 0 -1\n\
+        \x20 outer loop\n\
+        \x20   vertex 0 1 0\n\
+        \x20   vertex -1 0 0\n\
+        \x20   vertex 1 0 0\n\
+        \x20 endloop\n\
+        endfacet\n\
+        endsolid triangle\n";
+
+    fn binary_triangles(count: u32, truncate: usize) -> Vec<u8> {
+        let mut content = b"solid but actually binary".to_vec();
+        content.resize(80, 0);
+        content.extend(count.to_le_bytes());
+        for _ in 0..count {
+            for value in [0f32, 0., -1., 0., 1., 0., -1., 0., 0., 1., 0., 0.] {
+                content.extend(value.to_le_bytes());
+            }
+            content.extend([0, 0]);
+        }
+        content.truncate(content.len() - truncate);
+        content
+    }
+
+    fn assert_unit_triangle(group: &Group) {
+        let ray = Ray::new(Point::new(0., 0.5, -2.), UnitVector::Z);
+        let xs = group.intersect_with(&ray);
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 2.);
+        assert_eq!(
+            xs[0].normal_at(&Point::new(0., 0.5, 0.)),
+            Normal::new(0., 0., -1.)
+        );
+    }
+
+    #[test]
+    fn read_ascii_stl() {
+        let group = parse_stl(ASCII_TRIANGLE.as_bytes()).unwrap().into_group();
+
+        assert_eq!(group.children().len(), 1);
+        assert_unit_triangle(&group);
+    }
+
+    #[test]
+    fn read_binary_stl_starting_with_solid() {
+        let group = parse_stl(&binary_triangles(2, 0)).unwrap().into_group();
+
+        assert_eq!(group.children().len(), 2);
+        assert_eq!(
+            group.bounds(),
+            Aabb::new(Point::new(-1., 0., 0.), Point::new(1., 1., 0.))
+        );
+        assert_unit_triangle(&parse_stl(&binary_triangles(1, 0)).unwrap().into_group());
+    }
+
+    #[test]
+    fn read_stl_as_mesh() {
+        let mesh = parse_stl(&binary_triangles(2, 0)).unwrap().into_mesh();
+
+        assert_eq!(mesh.face_count(), 2);
+        let ray = Ray::new(Point::new(0., 0.5, -2.), UnitVector::Z);
+        let xs = mesh.intersect_with(&ray);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(
+            xs[0].normal_at(&Point::new(0., 0.5, 0.)),
+            Normal::new(0., 0., -1.)
+        );
+    }
+
+    #[test]
+    fn degenerate_facets_are_skipped_with_warnings() {
+        let content = ASCII_TRIANGLE.replace("vertex 0 1 0", "vertex 0 0 0");
+        let stl = parse_stl(content.as_bytes()).unwrap();
+
+        assert_eq!(stl.face_count(), 0);
+        assert_eq!(stl.warnings, vec!["facet 0: skipped degenerate triangle"]);
+    }
+
+    #[test]
+    fn truncated_files_are_rejected() {
+        let error = parse_stl(&binary_triangles(2, 10)).err().unwrap();
+        assert!(error.to_string().starts_with("ASCII STL is not valid text"));
+
+        let mut content = binary_triangles(2, 10);
+        content[..5].copy_from_slice(b"model");
+        let error = parse_stl(&content).err().unwrap();
+        assert_eq!(error.to_string(), "unexpected end of data at byte 94");
+
+        let error = parse_stl(&ASCII_TRIANGLE.as_bytes()[..60]).err().unwrap();
+        assert_eq!(error.to_string(), "line 4: unexpected end of file");
+    }
+
+    #[test]
+    fn malformed_ascii_is_rejected() {
+        let content = ASCII_TRIANGLE.replace("vertex -1 0 0", "vertex -1 zero 0");
+        let error = parse_stl(content.as_bytes()).err().unwrap();
+        assert_eq!(error.to_string(), "line 5: expected a number, found 'zero'");
+
+        let content = ASCII_TRIANGLE.replace("endloop", "end loop");
+        let error = parse_stl(content.as_bytes()).err().unwrap();
+        assert_eq!(error.to_string(), "line 7: expected 'endloop', found 'end'");
+    }
+}
@@ -0,0 +1,7 @@
+pub mod drawing;
+pub mod geometry;
+pub mod import;
+pub mod patterns;
+pub mod ppm;
+pub mod raycaster;
+pub mod shapes;
@@ -1,25 +1,11 @@
 use std::f64::consts::PI;
 
-use drawing::Color;
-
-use geometry::Point;
-
-use geometry::Vector;
-use ppm::write_ppm;
-use raycaster::Camera;
-use raycaster::PointLight;
-
-use raycaster::World;
-use shapes::Material;
-use shapes::Plane;
-use shapes::Sphere;
-use shapes::Transform;
-
-mod drawing;
-mod geometry;
-mod ppm;
-mod raycaster;
-mod shapes;
+use raytracer_challenge::drawing::Color;
+use raytracer_challenge::geometry::{Point, Vector};
+use raytracer_challenge::patterns::Checkers;
+use raytracer_challenge::ppm::write_ppm;
+use raytracer_challenge::raycaster::{Camera, PointLight, World};
+use raytracer_challenge::shapes::{Material, Plane, Sphere, Transform};
 
 fn create_camera(c_width: usize, c_height: usize) -> Camera {
     Camera::new(c_width, c_height, PI / 3.).with_transform(
@@ -84,14 +70,19 @@ fn right_wall() -> Plane {
 }
 
 fn floor() -> Plane {
-    let mut material = Material::default_with_color(Color::new(1., 0.9, 0.9));
+    let mut material = Material::default();
     material.specular = 0.;
+    material.pattern = Some(Box::new(Checkers::new(
+        Color::new(1., 0.9, 0.9),
+        Color::new(0.6, 0.5, 0.5),
+    )));
     Plane::new().with_material(material)
 }
 
 fn main() {
     let light = PointLight::new(Color::WHITE, Point::new(-10., 10., -10.));
-    let mut world = World::new(light);
+    let mut world = World::new();
+    world.add_light(light);
     world.add_shape(Box::new(floor()));
     world.add_shape(Box::new(left_wall()));
     world.add_shape(Box::new(right_wall()));
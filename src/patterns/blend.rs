@@ -0,0 +1,75 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::Pattern;
+
+pub struct Blend {
+    a: Box<dyn Pattern>,
+    b: Box<dyn Pattern>,
+    ratio: f64,
+    transform: Transform,
+}
+
+impl Blend {
+    pub fn new(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Blend {
+        Blend {
+            a: Box::new(a),
+            b: Box::new(b),
+            ratio: 0.5,
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_ratio(mut self, ratio: f64) -> Self {
+        self.ratio = ratio;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for Blend {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        self.a.color_at(pt) * (1. - self.ratio) + self.b.color_at(pt) * self.ratio
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    use crate::patterns::Stripes;
+
+    fn crossed_stripes() -> Blend {
+        let along_x = Stripes::new(Color::WHITE, Color::BLACK);
+        let along_z =
+            Stripes::new(Color::WHITE, Color::BLACK).with_transform(Transform::rotate_y(PI / 2.));
+        Blend::new(along_x, along_z)
+    }
+
+    #[test]
+    fn blend_averages_two_patterns() {
+        let blend = crossed_stripes();
+
+        assert_eq!(blend.color_at(&Point::new(0.5, 0., -0.5)), Color::WHITE);
+        assert_eq!(
+            blend.color_at(&Point::new(0.5, 0., 0.5)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(blend.color_at(&Point::new(1.5, 0., 0.5)), Color::BLACK);
+    }
+
+    #[test]
+    fn blend_ratio_weights_second_pattern() {
+        let blend = Blend::new(Color::WHITE, Color::new(1., 0., 0.)).with_ratio(0.25);
+
+        assert_eq!(blend.color_at(&Point::ZERO), Color::new(1., 0.75, 0.75));
+    }
+}
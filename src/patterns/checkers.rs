@@ -0,0 +1,89 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::Pattern;
+
+pub struct Checkers {
+    a: Box<dyn Pattern>,
+    b: Box<dyn Pattern>,
+    transform: Transform,
+}
+
+impl Checkers {
+    pub fn new(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Checkers {
+        Checkers {
+            a: Box::new(a),
+            b: Box::new(b),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for Checkers {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let sum = pt.x().floor() + pt.y().floor() + pt.z().floor();
+        if sum.rem_euclid(2.) == 0. {
+            self.a.color_at(pt)
+        } else {
+            self.b.color_at(pt)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::Stripes;
+
+    fn checkers() -> Checkers {
+        Checkers::new(Color::WHITE, Color::BLACK)
+    }
+
+    #[test]
+    fn checkers_repeat_in_x() {
+        assert_eq!(checkers().color_at(&Point::new(0., 0., 0.)), Color::WHITE);
+        assert_eq!(checkers().color_at(&Point::new(0.99, 0., 0.)), Color::WHITE);
+        assert_eq!(checkers().color_at(&Point::new(1.01, 0., 0.)), Color::BLACK);
+    }
+
+    #[test]
+    fn checkers_repeat_in_y() {
+        assert_eq!(checkers().color_at(&Point::new(0., 0.99, 0.)), Color::WHITE);
+        assert_eq!(checkers().color_at(&Point::new(0., 1.01, 0.)), Color::BLACK);
+    }
+
+    #[test]
+    fn checkers_repeat_in_z() {
+        assert_eq!(checkers().color_at(&Point::new(0., 0., 0.99)), Color::WHITE);
+        assert_eq!(checkers().color_at(&Point::new(0., 0., 1.01)), Color::BLACK);
+    }
+
+    #[test]
+    fn checkers_alternate_across_negative_coordinates() {
+        assert_eq!(checkers().color_at(&Point::new(-0.5, 0., 0.)), Color::BLACK);
+        assert_eq!(
+            checkers().color_at(&Point::new(-0.5, -0.5, 0.)),
+            Color::WHITE
+        );
+    }
+
+    #[test]
+    fn squares_can_hold_nested_patterns() {
+        let red = Color::new(1., 0., 0.);
+        let stripes =
+            Stripes::new(Color::WHITE, red.clone()).with_transform(Transform::scale(0.25, 1., 1.));
+        let checkers = Checkers::new(stripes, Color::BLACK);
+
+        assert_eq!(checkers.color_at(&Point::new(0.1, 0.5, 0.5)), Color::WHITE);
+        assert_eq!(checkers.color_at(&Point::new(0.3, 0.5, 0.5)), red);
+        assert_eq!(checkers.color_at(&Point::new(1.3, 0.5, 0.5)), Color::BLACK);
+    }
+}
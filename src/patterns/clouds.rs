@@ -0,0 +1,71 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::{Noise, Pattern};
+
+pub struct Clouds {
+    sky: Box<dyn Pattern>,
+    cloud: Box<dyn Pattern>,
+    noise: Noise,
+    transform: Transform,
+}
+
+impl Clouds {
+    pub fn new(sky: impl Pattern + 'static, cloud: impl Pattern + 'static) -> Clouds {
+        Clouds {
+            sky: Box::new(sky),
+            cloud: Box::new(cloud),
+            noise: Noise::new(1., 6),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_noise(mut self, noise: Noise) -> Self {
+        self.noise = noise;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for Clouds {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let density = ((self.noise.fbm(pt) + 1.) / 2.).clamp(0., 1.);
+        let sky = self.sky.color_at(pt);
+        &sky + (self.cloud.color_at(pt) - &sky) * density
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clouds() -> Clouds {
+        Clouds::new(Color::new(0.2, 0.4, 1.), Color::WHITE)
+    }
+
+    #[test]
+    fn density_is_half_at_lattice_points() {
+        assert_eq!(
+            clouds().color_at(&Point::new(2., 0., -3.)),
+            Color::new(0.6, 0.7, 1.)
+        );
+    }
+
+    #[test]
+    fn texture_follows_pattern_transform() {
+        let pt = Point::new(0.3, 0.45, 0.8);
+        let moved = clouds().with_transform(Transform::translate(5., 0., 0.));
+
+        assert_eq!(
+            moved.color_at(&Point::new(5.3, 0.45, 0.8)),
+            clouds().color_at(&pt)
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::{Pattern, UvPattern};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubeFace {
+    Left,
+    Front,
+    Right,
+    Back,
+    Up,
+    Down,
+}
+
+impl CubeFace {
+    pub fn of(pt: &Point) -> CubeFace {
+        let (x, y, z) = (pt.x(), pt.y(), pt.z());
+        let coord = x.abs().max(y.abs()).max(z.abs());
+        if coord == x {
+            CubeFace::Right
+        } else if coord == -x {
+            CubeFace::Left
+        } else if coord == y {
+            CubeFace::Up
+        } else if coord == -y {
+            CubeFace::Down
+        } else if coord == z {
+            CubeFace::Front
+        } else {
+            CubeFace::Back
+        }
+    }
+
+    pub fn uv_at(&self, pt: &Point) -> (f64, f64) {
+        let (x, y, z) = (pt.x(), pt.y(), pt.z());
+        let wrap = |coord: f64| coord.rem_euclid(2.) / 2.;
+        match self {
+            CubeFace::Front => (wrap(x + 1.), wrap(y + 1.)),
+            CubeFace::Back => (wrap(1. - x), wrap(y + 1.)),
+            CubeFace::Left => (wrap(z + 1.), wrap(y + 1.)),
+            CubeFace::Right => (wrap(1. - z), wrap(y + 1.)),
+            CubeFace::Up => (wrap(x + 1.), wrap(1. - z)),
+            CubeFace::Down => (wrap(x + 1.), wrap(z + 1.)),
+        }
+    }
+}
+
+pub struct CubeMap {
+    faces: [Box<dyn UvPattern>; 6],
+    transform: Transform,
+}
+
+impl CubeMap {
+    pub fn new(
+        left: impl UvPattern + 'static,
+        front: impl UvPattern + 'static,
+        right: impl UvPattern + 'static,
+        back: impl UvPattern + 'static,
+        up: impl UvPattern + 'static,
+        down: impl UvPattern + 'static,
+    ) -> CubeMap {
+        CubeMap {
+            faces: [
+                Box::new(left),
+                Box::new(front),
+                Box::new(right),
+                Box::new(back),
+                Box::new(up),
+                Box::new(down),
+            ],
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for CubeMap {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let face = CubeFace::of(pt);
+        let (u, v) = face.uv_at(pt);
+        self.faces[face as usize].uv_color_at(u, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::UvCheckers;
+
+    #[test]
+    fn identify_face_from_point() {
+        assert_eq!(CubeFace::of(&Point::new(-1., 0.5, -0.25)), CubeFace::Left);
+        assert_eq!(CubeFace::of(&Point::new(1.1, -0.75, 0.8)), CubeFace::Right);
+        assert_eq!(CubeFace::of(&Point::new(0.1, 0.6, 0.9)), CubeFace::Front);
+        assert_eq!(CubeFace::of(&Point::new(-0.7, 0., -2.)), CubeFace::Back);
+        assert_eq!(CubeFace::of(&Point::new(0.5, 1., 0.9)), CubeFace::Up);
+        assert_eq!(CubeFace::of(&Point::new(-0.2, -1.3, 1.1)), CubeFace::Down);
+    }
+
+    #[test]
+    fn uv_mapping_of_faces() {
+        assert_eq!(
+            CubeFace::Front.uv_at(&Point::new(-0.5, 0.5, 1.)),
+            (0.25, 0.75)
+        );
+        assert_eq!(
+            CubeFace::Back.uv_at(&Point::new(0.5, -0.5, -1.)),
+            (0.25, 0.25)
+        );
+        assert_eq!(
+            CubeFace::Left.uv_at(&Point::new(-1., -0.5, 0.5)),
+            (0.75, 0.25)
+        );
+        assert_eq!(
+            CubeFace::Right.uv_at(&Point::new(1., 0.5, -0.5)),
+            (0.75, 0.75)
+        );
+        assert_eq!(
+            CubeFace::Up.uv_at(&Point::new(-0.5, 1., -0.5)),
+            (0.25, 0.75)
+        );
+        assert_eq!(
+            CubeFace::Down.uv_at(&Point::new(0.5, -1., 0.5)),
+            (0.75, 0.75)
+        );
+    }
+
+    #[test]
+    fn each_face_uses_its_own_texture() {
+        let colors: Vec<Color> = (0..6).map(|i| Color::new(i as f64 / 5., 0., 0.)).collect();
+        let map = CubeMap::new(
+            colors[0].clone(),
+            colors[1].clone(),
+            colors[2].clone(),
+            colors[3].clone(),
+            colors[4].clone(),
+            colors[5].clone(),
+        );
+
+        assert_eq!(map.color_at(&Point::new(-1., 0., 0.)), colors[0]);
+        assert_eq!(map.color_at(&Point::new(0., 0., 1.)), colors[1]);
+        assert_eq!(map.color_at(&Point::new(1., 0., 0.)), colors[2]);
+        assert_eq!(map.color_at(&Point::new(0., 0., -1.)), colors[3]);
+        assert_eq!(map.color_at(&Point::new(0., 1., 0.)), colors[4]);
+        assert_eq!(map.color_at(&Point::new(0., -1., 0.)), colors[5]);
+    }
+
+    #[test]
+    fn faces_are_sampled_in_uv_space() {
+        let checkers = || UvCheckers::new(2., 2., Color::BLACK, Color::WHITE);
+        let map = CubeMap::new(
+            checkers(),
+            checkers(),
+            checkers(),
+            checkers(),
+            checkers(),
+            checkers(),
+        );
+
+        assert_eq!(map.color_at(&Point::new(-0.5, -0.5, 1.)), Color::BLACK);
+        assert_eq!(map.color_at(&Point::new(0.5, -0.5, 1.)), Color::WHITE);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::Pattern;
+
+pub struct Gradient {
+    from: Box<dyn Pattern>,
+    to: Box<dyn Pattern>,
+    transform: Transform,
+}
+
+impl Gradient {
+    pub fn new(from: impl Pattern + 'static, to: impl Pattern + 'static) -> Gradient {
+        Gradient {
+            from: Box::new(from),
+            to: Box::new(to),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for Gradient {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let fraction = pt.x() - pt.x().floor();
+        let from = self.from.color_at(pt);
+        let to = self.to.color_at(pt);
+        &from + (to - &from) * fraction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::Stripes;
+
+    fn gradient() -> Gradient {
+        Gradient::new(Color::WHITE, Color::BLACK)
+    }
+
+    #[test]
+    fn gradient_interpolates_between_colors() {
+        assert_eq!(gradient().color_at(&Point::new(0., 0., 0.)), Color::WHITE);
+        assert_eq!(
+            gradient().color_at(&Point::new(0.25, 0., 0.)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+        assert_eq!(
+            gradient().color_at(&Point::new(0.5, 0., 0.)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(
+            gradient().color_at(&Point::new(0.75, 0., 0.)),
+            Color::new(0.25, 0.25, 0.25)
+        );
+    }
+
+    #[test]
+    fn gradient_repeats_every_unit() {
+        assert_eq!(
+            gradient().color_at(&Point::new(1.25, 3., -2.)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+        assert_eq!(
+            gradient().color_at(&Point::new(-0.75, 0., 0.)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+    }
+
+    #[test]
+    fn gradient_between_nested_patterns() {
+        let from =
+            Stripes::new(Color::WHITE, Color::BLACK).with_transform(Transform::scale(0.5, 1., 1.));
+        let gradient = Gradient::new(from, Color::BLACK);
+
+        assert_eq!(
+            gradient.color_at(&Point::new(0.25, 0., 0.)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+        assert_eq!(gradient.color_at(&Point::new(0.75, 0., 0.)), Color::BLACK);
+    }
+}
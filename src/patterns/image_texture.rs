@@ -0,0 +1,166 @@
+use crate::drawing::{Canvas, Color};
+
+use super::UvPattern;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    Nearest,
+    Bilinear,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wrap {
+    Repeat,
+    Clamp,
+    Mirror,
+}
+
+impl Wrap {
+    fn apply(&self, index: i64, size: usize) -> usize {
+        let size = size as i64;
+        let wrapped = match self {
+            Wrap::Repeat => index.rem_euclid(size),
+            Wrap::Clamp => index.clamp(0, size - 1),
+            Wrap::Mirror => {
+                let index = index.rem_euclid(2 * size);
+                if index < size {
+                    index
+                } else {
+                    2 * size - 1 - index
+                }
+            }
+        };
+        wrapped as usize
+    }
+}
+
+pub struct ImageTexture {
+    canvas: Canvas,
+    filter: Filter,
+    wrap: Wrap,
+}
+
+impl ImageTexture {
+    pub fn new(canvas: Canvas) -> ImageTexture {
+        ImageTexture {
+            canvas,
+            filter: Filter::Bilinear,
+            wrap: Wrap::Repeat,
+        }
+    }
+
+    pub fn with_filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn with_wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    fn texel(&self, x: i64, y: i64) -> &Color {
+        let x = self.wrap.apply(x, self.canvas.width());
+        let y = self.wrap.apply(y, self.canvas.height());
+        self.canvas.pixel_at(x, y)
+    }
+}
+
+impl UvPattern for ImageTexture {
+    fn uv_color_at(&self, u: f64, v: f64) -> Color {
+        let x = u * self.canvas.width() as f64;
+        let y = (1. - v) * self.canvas.height() as f64;
+        match self.filter {
+            Filter::Nearest => self.texel(x.floor() as i64, y.floor() as i64).clone(),
+            Filter::Bilinear => {
+                let (x, y) = (x - 0.5, y - 0.5);
+                let (x0, y0) = (x.floor(), y.floor());
+                let (tx, ty) = (x - x0, y - y0);
+                let (x0, y0) = (x0 as i64, y0 as i64);
+
+                let top = self.texel(x0, y0) * (1. - tx) + self.texel(x0 + 1, y0) * tx;
+                let bottom = self.texel(x0, y0 + 1) * (1. - tx) + self.texel(x0 + 1, y0 + 1) * tx;
+                top * (1. - ty) + bottom * ty
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Color = Color::new(1., 0., 0.);
+    const GREEN: Color = Color::new(0., 1., 0.);
+    const BLUE: Color = Color::new(0., 0., 1.);
+
+    fn two_by_two() -> Canvas {
+        let mut canvas = Canvas::new(2, 2);
+        canvas.write_pixel(0, 0, &RED);
+        canvas.write_pixel(1, 0, &GREEN);
+        canvas.write_pixel(0, 1, &BLUE);
+        canvas.write_pixel(1, 1, &Color::WHITE);
+        canvas
+    }
+
+    fn nearest(wrap: Wrap) -> ImageTexture {
+        ImageTexture::new(two_by_two())
+            .with_filter(Filter::Nearest)
+            .with_wrap(wrap)
+    }
+
+    #[test]
+    fn nearest_filter_picks_texel_under_uv() {
+        let texture = nearest(Wrap::Repeat);
+
+        assert_eq!(texture.uv_color_at(0.25, 0.75), RED);
+        assert_eq!(texture.uv_color_at(0.75, 0.75), GREEN);
+        assert_eq!(texture.uv_color_at(0.25, 0.25), BLUE);
+        assert_eq!(texture.uv_color_at(0.75, 0.25), Color::WHITE);
+    }
+
+    #[test]
+    fn bilinear_filter_blends_neighbouring_texels() {
+        let texture = ImageTexture::new(two_by_two()).with_filter(Filter::Bilinear);
+
+        assert_eq!(texture.uv_color_at(0.25, 0.75), RED);
+        assert_eq!(texture.uv_color_at(0.5, 0.75), Color::new(0.5, 0.5, 0.));
+        assert_eq!(texture.uv_color_at(0.5, 0.5), Color::new(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn repeat_wrap_tiles_the_image() {
+        let texture = nearest(Wrap::Repeat);
+
+        assert_eq!(texture.uv_color_at(1.25, 0.75), RED);
+        assert_eq!(texture.uv_color_at(-0.25, 0.75), GREEN);
+        assert_eq!(texture.uv_color_at(0.25, 1.25), BLUE);
+    }
+
+    #[test]
+    fn clamp_wrap_extends_edge_texels() {
+        let texture = nearest(Wrap::Clamp);
+
+        assert_eq!(texture.uv_color_at(1.75, 0.75), GREEN);
+        assert_eq!(texture.uv_color_at(-3., 0.75), RED);
+        assert_eq!(texture.uv_color_at(0.25, -1.), BLUE);
+    }
+
+    #[test]
+    fn mirror_wrap_reflects_the_image() {
+        let texture = nearest(Wrap::Mirror);
+
+        assert_eq!(texture.uv_color_at(1.25, 0.75), GREEN);
+        assert_eq!(texture.uv_color_at(1.75, 0.75), RED);
+        assert_eq!(texture.uv_color_at(-0.25, 0.75), RED);
+    }
+
+    #[test]
+    fn bilinear_filter_respects_wrap_mode_at_edges() {
+        let repeat = ImageTexture::new(two_by_two()).with_wrap(Wrap::Repeat);
+        let clamp = ImageTexture::new(two_by_two()).with_wrap(Wrap::Clamp);
+
+        assert_eq!(repeat.uv_color_at(0., 0.75), Color::new(0.5, 0.5, 0.));
+        assert_eq!(clamp.uv_color_at(0., 0.75), RED);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::{Noise, Pattern};
+
+pub struct Marble {
+    a: Box<dyn Pattern>,
+    b: Box<dyn Pattern>,
+    noise: Noise,
+    transform: Transform,
+}
+
+impl Marble {
+    pub fn new(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Marble {
+        Marble {
+            a: Box::new(a),
+            b: Box::new(b),
+            noise: Noise::new(5., 6),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_noise(mut self, noise: Noise) -> Self {
+        self.noise = noise;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for Marble {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let phase = pt.x() + self.noise.turbulence(pt);
+        let fraction = (phase.sin() + 1.) / 2.;
+        let a = self.a.color_at(pt);
+        &a + (self.b.color_at(pt) - &a) * fraction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    fn marble() -> Marble {
+        Marble::new(Color::WHITE, Color::BLACK)
+    }
+
+    #[test]
+    fn veins_follow_sine_without_turbulence() {
+        let marble = marble().with_noise(Noise::new(0., 6));
+
+        assert_eq!(
+            marble.color_at(&Point::new(0., 3., 1.)),
+            Color::new(0.5, 0.5, 0.5)
+        );
+        assert_eq!(marble.color_at(&Point::new(PI / 2., 0., 0.)), Color::BLACK);
+        assert_eq!(marble.color_at(&Point::new(-PI / 2., 0., 0.)), Color::WHITE);
+    }
+
+    #[test]
+    fn turbulence_distorts_veins() {
+        let straight = marble().with_noise(Noise::new(0., 6));
+        let distorted = marble();
+        let pt = Point::new(0.3, 0.7, 0.2);
+
+        assert_ne!(straight.color_at(&pt), distorted.color_at(&pt));
+    }
+}
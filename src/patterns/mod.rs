@@ -0,0 +1,31 @@
+mod blend;
+mod checkers;
+mod clouds;
+mod cube_map;
+mod gradient;
+mod image_texture;
+mod marble;
+mod noise;
+mod pattern;
+mod perturb;
+mod rings;
+mod stripes;
+mod texture_map;
+mod uv;
+mod wood;
+
+pub use blend::Blend;
+pub use checkers::Checkers;
+pub use clouds::Clouds;
+pub use cube_map::{CubeFace, CubeMap};
+pub use gradient::Gradient;
+pub use image_texture::{Filter, ImageTexture, Wrap};
+pub use marble::Marble;
+pub use noise::{Noise, Perlin};
+pub use pattern::Pattern;
+pub use perturb::Perturb;
+pub use rings::Rings;
+pub use stripes::Stripes;
+pub use texture_map::TextureMap;
+pub use uv::{UvCheckers, UvMapping, UvPattern};
+pub use wood::Wood;
@@ -0,0 +1,226 @@
+use crate::geometry::{Point, Vector};
+
+pub struct Perlin {
+    permutation: [u8; 512],
+}
+
+pub struct Noise {
+    perlin: Perlin,
+    amount: f64,
+    octaves: u32,
+}
+
+impl Noise {
+    const DEFAULT_SEED: u64 = 0;
+
+    pub fn new(amount: f64, octaves: u32) -> Noise {
+        Noise {
+            perlin: Perlin::new(Self::DEFAULT_SEED),
+            amount,
+            octaves,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.perlin = Perlin::new(seed);
+        self
+    }
+
+    pub fn fbm(&self, pt: &Point) -> f64 {
+        self.amount * self.perlin.fbm(pt, self.octaves)
+    }
+
+    pub fn turbulence(&self, pt: &Point) -> f64 {
+        self.amount * self.perlin.turbulence(pt, self.octaves)
+    }
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Perlin {
+        let mut values: [u8; 256] = std::array::from_fn(|i| i as u8);
+        let mut state = seed;
+        for i in (1..values.len()).rev() {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let j = (state >> 33) as usize % (i + 1);
+            values.swap(i, j);
+        }
+
+        Perlin {
+            permutation: std::array::from_fn(|i| values[i % 256]),
+        }
+    }
+
+    pub fn noise(&self, pt: &Point) -> f64 {
+        let (xi, xf) = Self::split(pt.x());
+        let (yi, yf) = Self::split(pt.y());
+        let (zi, zf) = Self::split(pt.z());
+        let (u, v, w) = (Self::fade(xf), Self::fade(yf), Self::fade(zf));
+
+        let p = |i: usize| self.permutation[i] as usize;
+        let a = p(xi) + yi;
+        let (aa, ab) = (p(a) + zi, p(a + 1) + zi);
+        let b = p(xi + 1) + yi;
+        let (ba, bb) = (p(b) + zi, p(b + 1) + zi);
+
+        let grad = |hash: usize, dx: f64, dy: f64, dz: f64| Self::grad(p(hash), dx, dy, dz);
+        // Improved Perlin noise can slightly overshoot [-1, 1] near cell diagonals
+        let value = lerp(
+            w,
+            lerp(
+                v,
+                lerp(u, grad(aa, xf, yf, zf), grad(ba, xf - 1., yf, zf)),
+                lerp(u, grad(ab, xf, yf - 1., zf), grad(bb, xf - 1., yf - 1., zf)),
+            ),
+            lerp(
+                v,
+                lerp(
+                    u,
+                    grad(aa + 1, xf, yf, zf - 1.),
+                    grad(ba + 1, xf - 1., yf, zf - 1.),
+                ),
+                lerp(
+                    u,
+                    grad(ab + 1, xf, yf - 1., zf - 1.),
+                    grad(bb + 1, xf - 1., yf - 1., zf - 1.),
+                ),
+            ),
+        );
+        value.clamp(-1., 1.)
+    }
+
+    pub fn fbm(&self, pt: &Point, octaves: u32) -> f64 {
+        self.octaves(pt, octaves, |value| value)
+    }
+
+    pub fn turbulence(&self, pt: &Point, octaves: u32) -> f64 {
+        self.octaves(pt, octaves, f64::abs)
+    }
+
+    fn octaves(&self, pt: &Point, octaves: u32, shape: impl Fn(f64) -> f64) -> f64 {
+        let mut sum = 0.;
+        let mut total_amplitude = 0.;
+        let mut amplitude = 1.;
+        let mut frequency = 1.;
+        for _ in 0..octaves {
+            let sample = Point::from(Vector::from(*pt) * frequency);
+            sum += amplitude * shape(self.noise(&sample));
+            total_amplitude += amplitude;
+            amplitude *= 0.5;
+            frequency *= 2.;
+        }
+        if total_amplitude == 0. {
+            0.
+        } else {
+            sum / total_amplitude
+        }
+    }
+
+    fn split(coord: f64) -> (usize, f64) {
+        let floor = coord.floor();
+        ((floor as i64).rem_euclid(256) as usize, coord - floor)
+    }
+
+    fn fade(t: f64) -> f64 {
+        t * t * t * (t * (t * 6. - 15.) + 10.)
+    }
+
+    fn grad(hash: usize, x: f64, y: f64, z: f64) -> f64 {
+        let h = hash & 15;
+        let u = if h < 8 { x } else { y };
+        let v = match h {
+            0..=3 => y,
+            12 | 14 => x,
+            _ => z,
+        };
+        let u = if h & 1 == 0 { u } else { -u };
+        let v = if h & 2 == 0 { v } else { -v };
+        u + v
+    }
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_points() -> impl Iterator<Item = Point> {
+        (0..1000).map(|i| {
+            let i = i as f64;
+            Point::new(i * 0.137 - 50., i * 0.291 - 120., i * 0.053 + 7.)
+        })
+    }
+
+    #[test]
+    fn noise_vanishes_at_lattice_points() {
+        let perlin = Perlin::new(1);
+        assert_eq!(perlin.noise(&Point::new(0., 0., 0.)), 0.);
+        assert_eq!(perlin.noise(&Point::new(3., -7., 12.)), 0.);
+    }
+
+    #[test]
+    fn noise_stays_within_unit_range() {
+        let perlin = Perlin::new(7);
+        assert!(sample_points().all(|pt| perlin.noise(&pt).abs() <= 1.));
+        assert!(sample_points().any(|pt| perlin.noise(&pt).abs() > 0.1));
+    }
+
+    #[test]
+    fn same_seed_produces_same_noise() {
+        let a = Perlin::new(42);
+        let b = Perlin::new(42);
+        let c = Perlin::new(43);
+
+        assert!(sample_points().all(|pt| a.noise(&pt) == b.noise(&pt)));
+        assert!(sample_points().any(|pt| a.noise(&pt) != c.noise(&pt)));
+    }
+
+    #[test]
+    fn noise_is_continuous() {
+        let perlin = Perlin::new(3);
+        let pt = Point::new(1.999999, 0.5, 0.5);
+        let next = Point::new(2.000001, 0.5, 0.5);
+
+        assert!((perlin.noise(&pt) - perlin.noise(&next)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn single_octave_fbm_is_plain_noise() {
+        let perlin = Perlin::new(5);
+        assert!(sample_points().all(|pt| perlin.fbm(&pt, 1) == perlin.noise(&pt)));
+    }
+
+    #[test]
+    fn fbm_stays_within_unit_range() {
+        let perlin = Perlin::new(5);
+        assert!(sample_points().all(|pt| perlin.fbm(&pt, 6).abs() <= 1.));
+    }
+
+    #[test]
+    fn turbulence_is_non_negative() {
+        let perlin = Perlin::new(5);
+        assert!(sample_points().all(|pt| (0. ..=1.).contains(&perlin.turbulence(&pt, 6))));
+        assert!(sample_points().any(|pt| perlin.turbulence(&pt, 6) > 0.05));
+    }
+
+    #[test]
+    fn noise_parameters_scale_the_result() {
+        let noise = Noise::new(0.25, 4).with_seed(5);
+        let perlin = Perlin::new(5);
+
+        for pt in sample_points() {
+            assert_eq!(noise.fbm(&pt), 0.25 * perlin.fbm(&pt, 4));
+            assert!((0. ..=0.25).contains(&noise.turbulence(&pt)));
+        }
+    }
+
+    #[test]
+    fn zero_octaves_produce_no_noise() {
+        let perlin = Perlin::new(5);
+        assert_eq!(perlin.fbm(&Point::new(0.3, 0.6, 0.9), 0), 0.);
+    }
+}
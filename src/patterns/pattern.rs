@@ -0,0 +1,32 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+pub trait Pattern: Send + Sync {
+    fn transform(&self) -> &Transform;
+    fn local_color_at(&self, pt: &Point) -> Color;
+
+    fn color_at(&self, object_point: &Point) -> Color {
+        let pattern_point = self.transform().apply_inverse(object_point);
+        self.local_color_at(&pattern_point)
+    }
+}
+
+impl Pattern for Color {
+    fn transform(&self) -> &Transform {
+        &Transform::IDENTITY
+    }
+
+    fn local_color_at(&self, _pt: &Point) -> Color {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_is_a_solid_pattern() {
+        let color = Color::new(0.2, 0.4, 0.6);
+        assert_eq!(color.color_at(&Point::new(3., -7., 0.5)), color);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    drawing::Color,
+    geometry::{Point, Vector},
+    shapes::Transform,
+};
+
+use super::{Noise, Pattern};
+
+pub struct Perturb {
+    pattern: Box<dyn Pattern>,
+    noise: Noise,
+    transform: Transform,
+}
+
+impl Perturb {
+    pub fn new(pattern: impl Pattern + 'static, amount: f64) -> Perturb {
+        Perturb {
+            pattern: Box::new(pattern),
+            noise: Noise::new(amount, 1),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_noise(mut self, noise: Noise) -> Self {
+        self.noise = noise;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    fn jitter(&self, pt: &Point) -> Vector {
+        let dx = self.noise.fbm(pt);
+        let dy = self.noise.fbm(&(pt + Vector(31.7, 0., 0.)));
+        let dz = self.noise.fbm(&(pt + Vector(0., 0., 47.3)));
+        Vector(dx, dy, dz)
+    }
+}
+
+impl Pattern for Perturb {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let jittered = pt + self.jitter(pt);
+        self.pattern.color_at(&jittered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::Stripes;
+
+    fn stripes() -> Stripes {
+        Stripes::new(Color::WHITE, Color::BLACK)
+    }
+
+    fn sample_points() -> impl Iterator<Item = Point> {
+        (0..200).map(|i| Point::new(0.9 + i as f64 * 0.001, i as f64 * 0.37, i as f64 * 0.11))
+    }
+
+    #[test]
+    fn zero_amount_leaves_pattern_unchanged() {
+        let perturbed = Perturb::new(stripes(), 0.);
+
+        assert!(sample_points().all(|pt| perturbed.color_at(&pt) == stripes().color_at(&pt)));
+    }
+
+    #[test]
+    fn perturbation_moves_pattern_boundaries() {
+        let perturbed = Perturb::new(stripes(), 0.5);
+
+        assert!(sample_points().any(|pt| perturbed.color_at(&pt) != stripes().color_at(&pt)));
+    }
+
+    #[test]
+    fn perturbation_is_deterministic_per_seed() {
+        let a = Perturb::new(stripes(), 0.).with_noise(Noise::new(0.5, 1).with_seed(11));
+        let b = Perturb::new(stripes(), 0.).with_noise(Noise::new(0.5, 1).with_seed(11));
+
+        assert!(sample_points().all(|pt| a.color_at(&pt) == b.color_at(&pt)));
+    }
+}
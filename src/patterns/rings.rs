@@ -0,0 +1,60 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::Pattern;
+
+pub struct Rings {
+    a: Box<dyn Pattern>,
+    b: Box<dyn Pattern>,
+    transform: Transform,
+}
+
+impl Rings {
+    pub fn new(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Rings {
+        Rings {
+            a: Box::new(a),
+            b: Box::new(b),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for Rings {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let distance = pt.x().hypot(pt.z());
+        if distance.floor().rem_euclid(2.) == 0. {
+            self.a.color_at(pt)
+        } else {
+            self.b.color_at(pt)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rings() -> Rings {
+        Rings::new(Color::WHITE, Color::BLACK)
+    }
+
+    #[test]
+    fn rings_extend_in_x_and_z() {
+        assert_eq!(rings().color_at(&Point::new(0., 0., 0.)), Color::WHITE);
+        assert_eq!(rings().color_at(&Point::new(1., 0., 0.)), Color::BLACK);
+        assert_eq!(rings().color_at(&Point::new(0., 0., 1.)), Color::BLACK);
+        assert_eq!(
+            rings().color_at(&Point::new(0.708, 0., 0.708)),
+            Color::BLACK
+        );
+        assert_eq!(rings().color_at(&Point::new(2., 5., 0.)), Color::WHITE);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::Pattern;
+
+pub struct Stripes {
+    a: Box<dyn Pattern>,
+    b: Box<dyn Pattern>,
+    transform: Transform,
+}
+
+impl Stripes {
+    pub fn new(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Stripes {
+        Stripes {
+            a: Box::new(a),
+            b: Box::new(b),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for Stripes {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        if pt.x().floor().rem_euclid(2.) == 0. {
+            self.a.color_at(pt)
+        } else {
+            self.b.color_at(pt)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stripes() -> Stripes {
+        Stripes::new(Color::WHITE, Color::BLACK)
+    }
+
+    #[test]
+    fn stripes_are_constant_in_y_and_z() {
+        assert_eq!(stripes().color_at(&Point::new(0., 0., 0.)), Color::WHITE);
+        assert_eq!(stripes().color_at(&Point::new(0., 1., 0.)), Color::WHITE);
+        assert_eq!(stripes().color_at(&Point::new(0., 2., 0.)), Color::WHITE);
+        assert_eq!(stripes().color_at(&Point::new(0., 0., 1.)), Color::WHITE);
+        assert_eq!(stripes().color_at(&Point::new(0., 0., 2.)), Color::WHITE);
+    }
+
+    #[test]
+    fn stripes_alternate_in_x() {
+        assert_eq!(stripes().color_at(&Point::new(0., 0., 0.)), Color::WHITE);
+        assert_eq!(stripes().color_at(&Point::new(0.9, 0., 0.)), Color::WHITE);
+        assert_eq!(stripes().color_at(&Point::new(1., 0., 0.)), Color::BLACK);
+        assert_eq!(stripes().color_at(&Point::new(-0.1, 0., 0.)), Color::BLACK);
+        assert_eq!(stripes().color_at(&Point::new(-1., 0., 0.)), Color::BLACK);
+        assert_eq!(stripes().color_at(&Point::new(-1.1, 0., 0.)), Color::WHITE);
+    }
+
+    #[test]
+    fn pattern_transform_is_applied() {
+        let stripes =
+            Stripes::new(Color::WHITE, Color::BLACK).with_transform(Transform::scale(2., 2., 2.));
+
+        assert_eq!(stripes.color_at(&Point::new(1.5, 0., 0.)), Color::WHITE);
+        assert_eq!(stripes.color_at(&Point::new(2.5, 0., 0.)), Color::BLACK);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::{Pattern, UvMapping, UvPattern};
+
+pub struct TextureMap {
+    texture: Box<dyn UvPattern>,
+    mapping: UvMapping,
+    transform: Transform,
+}
+
+impl TextureMap {
+    pub fn new(texture: impl UvPattern + 'static, mapping: UvMapping) -> TextureMap {
+        TextureMap {
+            texture: Box::new(texture),
+            mapping,
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for TextureMap {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let (u, v) = self.mapping.uv_at(pt);
+        self.texture.uv_color_at(u, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::patterns::UvCheckers;
+
+    #[test]
+    fn spherical_checkers_on_a_sphere() {
+        let checkers = UvCheckers::new(16., 8., Color::BLACK, Color::WHITE);
+        let map = TextureMap::new(checkers, UvMapping::Spherical);
+
+        let samples = [
+            (Point::new(0.4315, 0.467, 0.7719), Color::WHITE),
+            (Point::new(-0.9654, 0.2552, -0.0534), Color::BLACK),
+            (Point::new(0.1039, 0.709, 0.6975), Color::WHITE),
+            (Point::new(-0.4986, -0.7856, -0.3663), Color::BLACK),
+            (Point::new(-0.0317, -0.9395, 0.3411), Color::BLACK),
+            (Point::new(0.4809, -0.7721, 0.4154), Color::BLACK),
+            (Point::new(0.0285, -0.9612, -0.2745), Color::BLACK),
+            (Point::new(-0.5734, -0.2162, -0.7903), Color::WHITE),
+            (Point::new(0.7688, -0.147, 0.6223), Color::BLACK),
+            (Point::new(-0.7652, 0.2175, 0.606), Color::BLACK),
+        ];
+        for (pt, expected) in samples {
+            assert_eq!(map.color_at(&pt), expected, "at {pt:?}");
+        }
+    }
+
+    #[test]
+    fn planar_texture_follows_pattern_transform() {
+        let checkers = UvCheckers::new(2., 2., Color::BLACK, Color::WHITE);
+        let map = TextureMap::new(checkers, UvMapping::Planar)
+            .with_transform(Transform::translate(0.5, 0., 0.));
+
+        assert_eq!(map.color_at(&Point::new(0.75, 0., 0.25)), Color::BLACK);
+        assert_eq!(map.color_at(&Point::new(0.25, 0., 0.25)), Color::WHITE);
+    }
+}
@@ -0,0 +1,149 @@
+use std::{f64::consts::PI, sync::Arc};
+
+use crate::{
+    drawing::Color,
+    geometry::{Point, Vector},
+};
+
+pub trait UvPattern: Send + Sync {
+    fn uv_color_at(&self, u: f64, v: f64) -> Color;
+}
+
+impl UvPattern for Color {
+    fn uv_color_at(&self, _u: f64, _v: f64) -> Color {
+        self.clone()
+    }
+}
+
+impl<T: UvPattern + ?Sized> UvPattern for Arc<T> {
+    fn uv_color_at(&self, u: f64, v: f64) -> Color {
+        self.as_ref().uv_color_at(u, v)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UvMapping {
+    Spherical,
+    Planar,
+    Cylindrical,
+}
+
+impl UvMapping {
+    pub fn uv_at(&self, pt: &Point) -> (f64, f64) {
+        match self {
+            UvMapping::Spherical => {
+                let radius = Vector::from(*pt).magnitude();
+                let phi = (pt.y() / radius).acos();
+                (azimuth(pt), 1. - phi / PI)
+            }
+            UvMapping::Planar => (pt.x().rem_euclid(1.), pt.z().rem_euclid(1.)),
+            UvMapping::Cylindrical => (azimuth(pt), pt.y().rem_euclid(1.)),
+        }
+    }
+}
+
+fn azimuth(pt: &Point) -> f64 {
+    let theta = pt.x().atan2(pt.z());
+    1. - (theta / (2. * PI) + 0.5)
+}
+
+pub struct UvCheckers {
+    width: f64,
+    height: f64,
+    a: Color,
+    b: Color,
+}
+
+impl UvCheckers {
+    pub const fn new(width: f64, height: f64, a: Color, b: Color) -> UvCheckers {
+        UvCheckers {
+            width,
+            height,
+            a,
+            b,
+        }
+    }
+}
+
+impl UvPattern for UvCheckers {
+    fn uv_color_at(&self, u: f64, v: f64) -> Color {
+        let sum = (u * self.width).floor() + (v * self.height).floor();
+        if sum.rem_euclid(2.) == 0. {
+            self.a.clone()
+        } else {
+            self.b.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::SQRT_2;
+
+    use super::*;
+
+    fn assert_uv(mapping: UvMapping, pt: Point, expected: (f64, f64)) {
+        let (u, v) = mapping.uv_at(&pt);
+        assert!(
+            (u - expected.0).abs() < 1e-5 && (v - expected.1).abs() < 1e-5,
+            "{pt:?} mapped to ({u}, {v}), expected {expected:?}"
+        );
+    }
+
+    #[test]
+    fn checkers_in_uv_space() {
+        let checkers = UvCheckers::new(2., 2., Color::BLACK, Color::WHITE);
+
+        assert_eq!(checkers.uv_color_at(0., 0.), Color::BLACK);
+        assert_eq!(checkers.uv_color_at(0.5, 0.), Color::WHITE);
+        assert_eq!(checkers.uv_color_at(0., 0.5), Color::WHITE);
+        assert_eq!(checkers.uv_color_at(0.5, 0.5), Color::BLACK);
+        assert_eq!(checkers.uv_color_at(1., 1.), Color::BLACK);
+    }
+
+    #[test]
+    fn spherical_mapping_on_unit_sphere() {
+        let mapping = UvMapping::Spherical;
+
+        assert_uv(mapping, Point::new(0., 0., -1.), (0., 0.5));
+        assert_uv(mapping, Point::new(1., 0., 0.), (0.25, 0.5));
+        assert_uv(mapping, Point::new(0., 0., 1.), (0.5, 0.5));
+        assert_uv(mapping, Point::new(-1., 0., 0.), (0.75, 0.5));
+        assert_uv(mapping, Point::new(0., 1., 0.), (0.5, 1.));
+        assert_uv(mapping, Point::new(0., -1., 0.), (0.5, 0.));
+        assert_uv(
+            mapping,
+            Point::new(SQRT_2 / 2., SQRT_2 / 2., 0.),
+            (0.25, 0.75),
+        );
+    }
+
+    #[test]
+    fn planar_mapping_tiles_the_plane() {
+        let mapping = UvMapping::Planar;
+
+        assert_uv(mapping, Point::new(0.25, 0., 0.5), (0.25, 0.5));
+        assert_uv(mapping, Point::new(0.25, 0., -0.25), (0.25, 0.75));
+        assert_uv(mapping, Point::new(0.25, 0.5, -0.25), (0.25, 0.75));
+        assert_uv(mapping, Point::new(1.25, 0., 0.5), (0.25, 0.5));
+        assert_uv(mapping, Point::new(0.25, 0., -1.75), (0.25, 0.25));
+        assert_uv(mapping, Point::new(1., 0., -1.), (0., 0.));
+    }
+
+    #[test]
+    fn cylindrical_mapping_wraps_around_y_axis() {
+        let mapping = UvMapping::Cylindrical;
+
+        assert_uv(mapping, Point::new(0., 0., -1.), (0., 0.));
+        assert_uv(mapping, Point::new(0., 0.5, -1.), (0., 0.5));
+        assert_uv(mapping, Point::new(0., 1., -1.), (0., 0.));
+        assert_uv(
+            mapping,
+            Point::new(SQRT_2 / 2., 0.5, -SQRT_2 / 2.),
+            (0.125, 0.5),
+        );
+        assert_uv(mapping, Point::new(1., 0.5, 0.), (0.25, 0.5));
+        assert_uv(mapping, Point::new(0., -0.25, 1.), (0.5, 0.75));
+        assert_uv(mapping, Point::new(-1., 1.25, 0.), (0.75, 0.25));
+    }
+}
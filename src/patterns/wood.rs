@@ -0,0 +1,76 @@
+use crate::{drawing::Color, geometry::Point, shapes::Transform};
+
+use super::{Noise, Pattern};
+
+pub struct Wood {
+    a: Box<dyn Pattern>,
+    b: Box<dyn Pattern>,
+    noise: Noise,
+    transform: Transform,
+}
+
+impl Wood {
+    pub fn new(a: impl Pattern + 'static, b: impl Pattern + 'static) -> Wood {
+        Wood {
+            a: Box::new(a),
+            b: Box::new(b),
+            noise: Noise::new(0.1, 3),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_noise(mut self, noise: Noise) -> Self {
+        self.noise = noise;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+}
+
+impl Pattern for Wood {
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_color_at(&self, pt: &Point) -> Color {
+        let distance = pt.x().hypot(pt.z()) + self.noise.fbm(pt);
+        let fraction = distance - distance.floor();
+        let a = self.a.color_at(pt);
+        &a + (self.b.color_at(pt) - &a) * fraction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wood() -> Wood {
+        Wood::new(Color::WHITE, Color::BLACK)
+    }
+
+    #[test]
+    fn rings_grow_outward_from_y_axis() {
+        let wood = wood().with_noise(Noise::new(0., 3));
+
+        assert_eq!(wood.color_at(&Point::new(0., 5., 0.)), Color::WHITE);
+        assert_eq!(wood.color_at(&Point::new(0.6, 0., 0.8)), Color::WHITE);
+        assert_eq!(
+            wood.color_at(&Point::new(0., -2., 1.25)),
+            Color::new(0.75, 0.75, 0.75)
+        );
+    }
+
+    #[test]
+    fn grain_varies_with_seed() {
+        let a = wood().with_noise(Noise::new(0.1, 3).with_seed(1));
+        let b = wood().with_noise(Noise::new(0.1, 3).with_seed(2));
+
+        let differs = (0..50)
+            .map(|i| Point::new(0.5 + i as f64 * 0.03, i as f64 * 0.11, 0.2))
+            .any(|pt| a.color_at(&pt) != b.color_at(&pt));
+        assert!(differs);
+    }
+}
@@ -1,10 +1,11 @@
 use std::fs::File;
-use std::io::Write;
+use std::io::{Error, ErrorKind, Read, Write};
 
 use crate::drawing::Canvas;
 use crate::drawing::Color;
 
 type WriteResult = Result<(), std::io::Error>;
+type ReadResult<T> = Result<T, std::io::Error>;
 
 pub fn write_ppm(filename: &str, canvas: &Canvas) -> WriteResult {
     File::create(filename)
@@ -12,6 +13,12 @@ pub fn write_ppm(filename: &str, canvas: &Canvas) -> WriteResult {
         .and(Ok(()))
 }
 
+pub fn read_ppm(filename: &str) -> ReadResult<Canvas> {
+    let mut content = vec![];
+    File::open(filename)?.read_to_end(&mut content)?;
+    PpmReader::new(&content).read_canvas()
+}
+
 struct PpmWriter<'a> {
     file: &'a File,
 }
@@ -48,8 +55,118 @@ impl PpmWriter<'_> {
 
     fn write_line(&mut self, line: &str) -> WriteResult {
         self.file.write_all(line.trim().as_bytes())?;
-        writeln!(self.file, "")
+        writeln!(self.file)
+    }
+}
+
+struct PpmReader<'a> {
+    content: &'a [u8],
+    pos: usize,
+}
+
+impl PpmReader<'_> {
+    fn new(content: &[u8]) -> PpmReader<'_> {
+        PpmReader { content, pos: 0 }
     }
+
+    fn read_canvas(&mut self) -> ReadResult<Canvas> {
+        let magic = self.next_token()?;
+        let width = self.next_number()?;
+        let height = self.next_number()?;
+        let max_value = self.next_number()?;
+        if max_value == 0 || max_value > u16::MAX as usize {
+            return Err(invalid_data(format!(
+                "invalid maximum color value {max_value}"
+            )));
+        }
+
+        if width == 0 || height == 0 {
+            return Err(invalid_data(format!("empty image size {width}x{height}")));
+        }
+        let channels = width
+            .checked_mul(height)
+            .and_then(|pixels| pixels.checked_mul(3))
+            .filter(|&channels| channels <= self.content.len() - self.pos)
+            .ok_or_else(|| {
+                invalid_data(format!("image size {width}x{height} exceeds pixel data"))
+            })?;
+        let values = match magic.as_str() {
+            "P3" => (0..channels)
+                .map(|_| self.next_number())
+                .collect::<ReadResult<Vec<_>>>()?,
+            "P6" => {
+                self.pos += 1;
+                self.binary_values(channels, max_value)?
+            }
+            _ => return Err(invalid_data(format!("unsupported PPM format {magic}"))),
+        };
+        if let Some(value) = values.iter().find(|&&value| value > max_value) {
+            return Err(invalid_data(format!(
+                "color value {value} exceeds maximum {max_value}"
+            )));
+        }
+
+        let mut canvas = Canvas::new(width, height);
+        for (index, rgb) in values.chunks(3).enumerate() {
+            let channel = |value: usize| value as f64 / max_value as f64;
+            let color = Color::new(channel(rgb[0]), channel(rgb[1]), channel(rgb[2]));
+            canvas.write_pixel(index % width, index / width, &color);
+        }
+        Ok(canvas)
+    }
+
+    fn binary_values(&mut self, count: usize, max_value: usize) -> ReadResult<Vec<usize>> {
+        let width = if max_value < 256 { 1 } else { 2 };
+        let data = count
+            .checked_mul(width)
+            .and_then(|len| {
+                self.content
+                    .get(self.pos..)
+                    .and_then(|rest| rest.get(..len))
+            })
+            .ok_or_else(|| invalid_data("unexpected end of pixel data".to_string()))?;
+        Ok(data
+            .chunks(width)
+            .map(|bytes| bytes.iter().fold(0, |acc, &b| acc * 256 + b as usize))
+            .collect())
+    }
+
+    fn next_number(&mut self) -> ReadResult<usize> {
+        let token = self.next_token()?;
+        token
+            .parse()
+            .map_err(|_| invalid_data(format!("expected a number, found '{token}'")))
+    }
+
+    fn next_token(&mut self) -> ReadResult<String> {
+        self.skip_whitespace_and_comments();
+        let start = self.pos;
+        while self.pos < self.content.len() && !self.content[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(invalid_data("unexpected end of file".to_string()));
+        }
+        Ok(String::from_utf8_lossy(&self.content[start..self.pos]).into_owned())
+    }
+
+    fn skip_whitespace_and_comments(&mut self) {
+        while let Some(&byte) = self.content.get(self.pos) {
+            if byte == b'#' {
+                while self.content.get(self.pos).is_some_and(|&b| b != b'\n') {
+                    self.pos += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
 }
 
 fn to_int(color_channel: f64) -> u8 {
@@ -112,6 +229,90 @@ mod tests {
         assert_eq!(content.last(), Some(&"".to_string()));
     }
 
+    mod reading {
+        use super::*;
+
+        fn read(content: &[u8]) -> ReadResult<Canvas> {
+            PpmReader::new(content).read_canvas()
+        }
+
+        #[test]
+        fn read_plain_ppm() {
+            let canvas = read(b"P3\n2 1\n255\n255 0 0  0 0 51\n").unwrap();
+
+            assert_eq!(canvas.width(), 2);
+            assert_eq!(canvas.height(), 1);
+            assert_eq!(canvas.pixel_at(0, 0), &Color::new(1., 0., 0.));
+            assert_eq!(canvas.pixel_at(1, 0), &Color::new(0., 0., 0.2));
+        }
+
+        #[test]
+        fn comments_and_custom_scale_are_supported() {
+            let canvas = read(b"P3\n# a comment\n1 2 # trailing\n100\n100 50 0\n0 25 100").unwrap();
+
+            assert_eq!(canvas.pixel_at(0, 0), &Color::new(1., 0.5, 0.));
+            assert_eq!(canvas.pixel_at(0, 1), &Color::new(0., 0.25, 1.));
+        }
+
+        #[test]
+        fn read_binary_ppm() {
+            let mut content = b"P6\n2 1\n255\n".to_vec();
+            content.extend([255, 0, 0, 0, 255, 51]);
+
+            let canvas = read(&content).unwrap();
+            assert_eq!(canvas.pixel_at(0, 0), &Color::new(1., 0., 0.));
+            assert_eq!(canvas.pixel_at(1, 0), &Color::new(0., 1., 0.2));
+        }
+
+        #[test]
+        fn truncated_data_is_an_error() {
+            assert!(read(b"P3\n2 1\n255\n255 0 0").is_err());
+            assert!(read(b"P6\n2 1\n255\n\x00\x01").is_err());
+        }
+
+        #[test]
+        fn oversized_dimensions_are_an_error() {
+            let huge = format!("P6\n{} {}\n255\n\x00", usize::MAX, usize::MAX);
+            assert!(read(huge.as_bytes()).is_err_and(|err| err.kind() == ErrorKind::InvalidData));
+            assert!(read(b"P3\n100000 100000\n255\n0 0 0").is_err());
+        }
+
+        #[test]
+        fn empty_images_are_an_error() {
+            for content in [&b"P3\n0 0\n255\n"[..], b"P3\n0 2\n255\n", b"P6\n2 0\n255\n"] {
+                let result = read(content);
+                assert!(result.is_err_and(|err| err.kind() == ErrorKind::InvalidData));
+            }
+        }
+
+        #[test]
+        fn samples_above_maximum_are_an_error() {
+            assert!(read(b"P3\n1 1\n100\n101 0 0").is_err());
+
+            let mut content = b"P6\n1 1\n300\n".to_vec();
+            content.extend([1, 45, 0, 0, 0, 0]);
+            assert!(read(&content).is_err());
+        }
+
+        #[test]
+        fn unsupported_format_is_an_error() {
+            let result = read(b"P5\n1 1\n255\n0");
+            assert!(result.is_err_and(|err| err.kind() == ErrorKind::InvalidData));
+        }
+
+        #[test]
+        fn written_canvas_can_be_read_back() {
+            let mut canvas = Canvas::new(3, 2);
+            canvas.write_pixel(1, 1, &Color::new(1., 0.8, 0.6));
+            let output = PpmWriter::write_file(tempfile(), &canvas).unwrap();
+
+            let content = read_file_lines(output).join("\n");
+            let read_back = read(content.as_bytes()).unwrap();
+            assert_eq!(read_back.pixel_at(1, 1), &Color::new(1., 0.8, 0.6));
+            assert_eq!(read_back.pixel_at(0, 0), &Color::BLACK);
+        }
+    }
+
     fn read_file_lines(mut f: File) -> Vec<String> {
         f.seek(SeekFrom::Start(0)).unwrap();
 
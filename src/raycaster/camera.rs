@@ -13,14 +13,7 @@ struct ViewTransform(Matrix);
 
 impl ViewTransform {
     fn new(from: &Point, to: &Point, up: &Vector) -> ViewTransform {
-        let view_z = (to - from).normalize();
-        let up_norm = up.normalize();
-        let view_x = up_norm.cross(&view_z).normalize();
-        let view_y = view_z.cross(&view_x).normalize();
-
-        let orientation = Matrix::from_vectors(&view_x, &view_y, &view_z);
-        let transform = Matrix::translation(from) * orientation;
-        ViewTransform(transform)
+        ViewTransform(Matrix::look_at(from, to, up))
     }
 
     fn default() -> ViewTransform {
@@ -66,18 +59,22 @@ impl Camera {
         self
     }
 
-    pub fn scan_space(&self, mut f: impl FnMut(&Ray, usize, usize) -> ()) {
+    pub fn scan_space(&self, mut f: impl FnMut(&Ray, usize, usize)) {
         for y in 0..self.v_size {
             for x in 0..self.h_size {
-                self.cast_ray_at(x, y, |r| f(&r, x, y));
+                self.cast_ray_at(x, y, |r| f(r, x, y));
             }
         }
     }
 
-    pub fn cast_ray_at(&self, px: usize, py: usize, mut f: impl FnMut(&Ray) -> ()) {
+    pub fn cast_ray_at(&self, px: usize, py: usize, mut f: impl FnMut(&Ray)) {
+        f(&self.ray_at(px, py))
+    }
+
+    pub fn ray_at(&self, px: usize, py: usize) -> Ray {
         let origin = self.transform.to_world(&Point::ZERO);
         let pixel = self.transform.to_world(&self.view_pixel_at(px, py));
-        f(&Ray::between(&origin, &pixel))
+        Ray::between(&origin, &pixel)
     }
 
     fn view_pixel_at(&self, px: usize, py: usize) -> Point {
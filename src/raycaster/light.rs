@@ -17,10 +17,10 @@ impl PointLight {
     }
 
     pub fn direction_from(&self, pt: &Point) -> UnitVector {
-        (&self.position - pt).normalize()
+        (self.position - pt).normalize()
     }
 
     pub fn distance_from(&self, point: &Point) -> f64 {
-        (&self.position - point).magnitude()
+        (self.position - point).magnitude()
     }
 }
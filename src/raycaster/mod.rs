@@ -4,4 +4,4 @@ mod world;
 
 pub use camera::Camera;
 pub use light::PointLight;
-pub use world::World;
+pub use world::{LightHandle, World};
@@ -1,106 +1,317 @@
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        OnceLock,
+    },
+    thread,
+};
+
 use crate::{
     drawing::{Canvas, Color},
-    geometry::{Normal, Point, Ray, UnitVector},
-    shapes::Shape,
+    geometry::{Bvh, Normal, Point, Ray, UnitVector},
+    shapes::{Intersection, Material, Shape},
 };
 
 use super::{Camera, PointLight};
 
 pub struct World {
-    light: PointLight,
+    lights: Vec<(LightHandle, PointLight)>,
+    next_light_handle: usize,
     shapes: Vec<Box<dyn Shape>>,
+    bvh: OnceLock<Bvh>,
+    max_depth: usize,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LightHandle(usize);
+
 struct RayHit<'a> {
-    shape: &'a Box<dyn Shape>,
-    shape_index: usize,
-    is_shadowed: bool,
+    material: &'a Material,
     point: Point,
+    object_point: Point,
+    over_point: Point,
+    under_point: Point,
     eye_direction: UnitVector,
     normal: Normal,
+    reflect_direction: UnitVector,
+    n1: f64,
+    n2: f64,
 }
 
-#[derive(Clone, Copy)]
-struct Intersection(usize, f64);
+struct ShapeIntersection<'a>(usize, Intersection<'a>);
 
 impl RayHit<'_> {
-    fn lightning(&self, light: &PointLight) -> Color {
-        self.shape.material().lighting(
+    fn schlick(&self) -> f64 {
+        let mut cos = self.eye_direction.dot(&self.normal.v());
+        if self.n1 > self.n2 {
+            let n_ratio = self.n1 / self.n2;
+            let sin2_t = n_ratio * n_ratio * (1. - cos * cos);
+            if sin2_t > 1. {
+                return 1.;
+            }
+            cos = (1. - sin2_t).sqrt();
+        }
+        let r0 = ((self.n1 - self.n2) / (self.n1 + self.n2)).powi(2);
+        r0 + (1. - r0) * (1. - cos).powi(5)
+    }
+
+    fn lightning(&self, light: &PointLight, is_shadowed: bool) -> Color {
+        self.material.lighting(
             light,
             &self.point,
+            &self.object_point,
             &self.eye_direction,
             &self.normal,
-            self.is_shadowed,
+            is_shadowed,
         )
     }
 }
 
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl World {
-    pub fn new(light: PointLight) -> World {
+    const DEFAULT_MAX_DEPTH: usize = 5;
+
+    pub fn new() -> World {
         World {
-            light,
+            lights: vec![],
+            next_light_handle: 0,
             shapes: vec![],
+            bvh: OnceLock::new(),
+            max_depth: Self::DEFAULT_MAX_DEPTH,
         }
     }
 
+    pub fn set_max_depth(&mut self, depth: usize) {
+        self.max_depth = depth;
+    }
+
+    pub fn add_light(&mut self, light: PointLight) -> LightHandle {
+        let handle = LightHandle(self.next_light_handle);
+        self.next_light_handle += 1;
+        self.lights.push((handle, light));
+        handle
+    }
+
+    pub fn remove_light(&mut self, handle: LightHandle) -> Option<PointLight> {
+        let position = self.lights.iter().position(|(h, _)| *h == handle)?;
+        Some(self.lights.remove(position).1)
+    }
+
     pub fn add_shape(&mut self, shape: Box<dyn Shape>) -> usize {
         self.shapes.push(shape);
+        self.bvh = OnceLock::new();
         self.shapes.len() - 1
     }
 
     pub fn render(&self, camera: &Camera) -> Canvas {
-        let mut canvas = Canvas::new(camera.h_size(), camera.v_size());
-        camera.scan_space(|ray, px, py| {
-            let point_color = self.get_color(&ray).unwrap_or(Color::BLACK);
-            canvas.write_pixel(px, py, &point_color);
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        self.render_with_threads(camera, threads)
+    }
+
+    pub fn render_with_threads(&self, camera: &Camera, threads: usize) -> Canvas {
+        self.bvh();
+        let next_row = AtomicUsize::new(0);
+        let render_rows = || {
+            let mut rows = vec![];
+            loop {
+                let py = next_row.fetch_add(1, Ordering::Relaxed);
+                if py >= camera.v_size() {
+                    return rows;
+                }
+                let colors: Vec<Color> = (0..camera.h_size())
+                    .map(|px| self.get_color(&camera.ray_at(px, py), self.max_depth))
+                    .map(|color| color.unwrap_or(Color::BLACK))
+                    .collect();
+                rows.push((py, colors));
+            }
+        };
+
+        let rows: Vec<(usize, Vec<Color>)> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..threads.max(1))
+                .map(|_| scope.spawn(render_rows))
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect()
         });
+
+        let mut canvas = Canvas::new(camera.h_size(), camera.v_size());
+        for (py, colors) in rows {
+            for (px, color) in colors.iter().enumerate() {
+                canvas.write_pixel(px, py, color);
+            }
+        }
         canvas
     }
 
-    fn get_color(&self, ray: &Ray) -> Option<Color> {
-        self.hit_with_ray(&ray)
-            .map(|hit| hit.lightning(&self.light))
+    fn get_color(&self, ray: &Ray, remaining_depth: usize) -> Option<Color> {
+        self.hit_with_ray(ray)
+            .map(|hit| self.shade_hit(&hit, remaining_depth))
+    }
+
+    fn shade_hit(&self, hit: &RayHit, remaining_depth: usize) -> Color {
+        let surface = self
+            .lights
+            .iter()
+            .map(|(_, light)| hit.lightning(light, self.is_shadowed(light, &hit.over_point)))
+            .fold(Color::BLACK, |acc, color| acc + color);
+        let reflected = self.reflected_color(hit, remaining_depth);
+        let refracted = self.refracted_color(hit, remaining_depth);
+
+        let material = hit.material;
+        if material.reflective > 0. && material.transparency > 0. {
+            let reflectance = hit.schlick();
+            surface + reflected * reflectance + refracted * (1. - reflectance)
+        } else {
+            surface + reflected + refracted
+        }
     }
 
-    fn is_shadowed(&self, point: &Point) -> bool {
-        let distance_to_light = self.light.distance_from(point);
-        let direction_to_light = self.light.direction_from(point);
-        let shadow_ray = Ray::new(point.clone(), direction_to_light);
+    fn reflected_color(&self, hit: &RayHit, remaining_depth: usize) -> Color {
+        let reflective = hit.material.reflective;
+        if reflective == 0. || remaining_depth == 0 {
+            return Color::BLACK;
+        }
+        let reflect_ray = Ray::new(hit.over_point, hit.reflect_direction);
+        let color = self
+            .get_color(&reflect_ray, remaining_depth - 1)
+            .unwrap_or(Color::BLACK);
+        color * reflective
+    }
+
+    fn refracted_color(&self, hit: &RayHit, remaining_depth: usize) -> Color {
+        let transparency = hit.material.transparency;
+        if transparency == 0. || remaining_depth == 0 {
+            return Color::BLACK;
+        }
+
+        let n_ratio = hit.n1 / hit.n2;
+        let cos_i = hit.eye_direction.dot(&hit.normal.v());
+        let sin2_t = n_ratio * n_ratio * (1. - cos_i * cos_i);
+        if sin2_t > 1. {
+            return Color::BLACK;
+        }
+
+        let cos_t = (1. - sin2_t).sqrt();
+        let direction =
+            hit.normal.v() * (n_ratio * cos_i - cos_t) - hit.eye_direction.v() * n_ratio;
+        let refract_ray = Ray::new(hit.under_point, direction.normalize());
+        let color = self
+            .get_color(&refract_ray, remaining_depth - 1)
+            .unwrap_or(Color::BLACK);
+        color * transparency
+    }
+
+    /// Finds the refractive indices of the media on both sides of the hit surface
+    /// by tracking which objects the ray is inside of up to the hit.
+    fn refractive_indices(&self, ray: &Ray, hit: &ShapeIntersection) -> (f64, f64) {
+        let mut intersections = vec![];
+        self.bvh().for_each_candidate(ray, hit.1.t, |i| {
+            let xs = self.shapes[i].intersect_with(ray);
+            intersections.extend(xs.into_iter().map(|x| ShapeIntersection(i, x)));
+        });
+        intersections.sort_by(|a, b| a.1.t.total_cmp(&b.1.t).then(a.0.cmp(&b.0)));
+
+        let refractive_index = |containers: &[Intersection]| {
+            containers
+                .last()
+                .map_or(1., |surface| surface.material().refractive_index)
+        };
+        let mut containers: Vec<Intersection> = vec![];
+        for ShapeIntersection(_, intersection) in intersections {
+            let is_hit = intersection.is_same_surface(&hit.1) && intersection.t == hit.1.t;
+            let n1 = refractive_index(&containers);
+            match containers
+                .iter()
+                .position(|surface| surface.is_same_surface(&intersection))
+            {
+                Some(position) => {
+                    containers.remove(position);
+                }
+                None => containers.push(intersection),
+            }
+            if is_hit {
+                return (n1, refractive_index(&containers));
+            }
+        }
+        (1., 1.)
+    }
+
+    fn is_shadowed(&self, light: &PointLight, point: &Point) -> bool {
+        let distance_to_light = light.distance_from(point);
+        let direction_to_light = light.direction_from(point);
+        let shadow_ray = Ray::new(*point, direction_to_light);
         self.first_intersection_with(&shadow_ray)
-            .map(|Intersection(_, distance)| distance < distance_to_light)
+            .map(|ShapeIntersection(_, intersection)| intersection.t < distance_to_light)
             .unwrap_or(false)
     }
 
-    fn hit_with_ray(&self, ray: &Ray) -> Option<RayHit> {
-        self.first_intersection_with(ray)
-            .map(|Intersection(shape_index, pos)| {
-                let shape = self.shapes.get(shape_index).unwrap();
-                let point = ray.position(pos);
-                let mut normal = shape.normal_at(&point);
-                let eye_direction = ray.direction.flip().normalize();
-                let is_inside = normal.dot(&eye_direction) < 0.;
-                if is_inside {
-                    normal = normal.flip()
-                }
-                let is_shadowed = self.is_shadowed(&normal.over_point(&point));
-
-                RayHit {
-                    shape,
-                    shape_index,
-                    point,
-                    normal,
-                    eye_direction,
-                    is_shadowed,
-                }
+    fn hit_with_ray(&self, ray: &Ray) -> Option<RayHit<'_>> {
+        self.first_intersection_with(ray).map(|intersection| {
+            let hit = &intersection.1;
+            let material = hit.material();
+            let point = ray.position(hit.t);
+            let mut normal = hit.normal_at(&point);
+            let eye_direction = ray.direction.flip().normalize();
+            let is_inside = normal.dot(&eye_direction) < 0.;
+            if is_inside {
+                normal = normal.flip()
+            }
+            let over_point = normal.over_point(&point);
+            let under_point = normal.under_point(&point);
+            let object_point = hit.world_to_object(&over_point);
+            let reflect_direction = normal.reflect(&eye_direction).normalize();
+            let (n1, n2) = if material.transparency > 0. {
+                self.refractive_indices(ray, &intersection)
+            } else {
+                (1., 1.)
+            };
+
+            RayHit {
+                material,
+                point,
+                object_point,
+                over_point,
+                under_point,
+                normal,
+                eye_direction,
+                reflect_direction,
+                n1,
+                n2,
+            }
+        })
+    }
+
+    fn first_intersection_with(&self, ray: &Ray) -> Option<ShapeIntersection<'_>> {
+        self.bvh()
+            .closest_hit(ray, |i| {
+                self.shapes[i]
+                    .intersect_with(ray)
+                    .into_iter()
+                    .filter(|x| x.t >= 0.)
+                    .min_by(|a, b| a.t.total_cmp(&b.t))
+                    .map(|x| (x.t, x))
             })
+            .map(|(shape_index, hit)| ShapeIntersection(shape_index, hit))
     }
 
-    fn first_intersection_with(&self, ray: &Ray) -> Option<Intersection> {
-        self.intersect_with(ray).first().copied()
+    fn bvh(&self) -> &Bvh {
+        self.bvh.get_or_init(|| {
+            let bounds: Vec<_> = self.shapes.iter().map(|shape| shape.bounds()).collect();
+            Bvh::build(&bounds)
+        })
     }
 
-    fn intersect_with(&self, ray: &Ray) -> Vec<Intersection> {
-        let mut intersections: Vec<Intersection> = self
+    #[cfg(test)]
+    fn intersect_with(&self, ray: &Ray) -> Vec<ShapeIntersection<'_>> {
+        let mut intersections: Vec<ShapeIntersection> = self
             .shapes
             .iter()
             .enumerate()
@@ -108,12 +319,12 @@ impl World {
                 shape
                     .intersect_with(ray)
                     .into_iter()
-                    .map(move |v| Intersection(i, v))
+                    .map(move |x| ShapeIntersection(i, x))
             })
-            .filter(|inter| inter.1 >= 0.)
+            .filter(|inter| inter.1.t >= 0.)
             .collect();
 
-        intersections.sort_by(|a, b| a.1.total_cmp(&b.1));
+        intersections.sort_by(|a, b| a.1.t.total_cmp(&b.1.t));
         intersections
     }
 }
@@ -133,13 +344,14 @@ mod tests {
     const LIGHT: PointLight = PointLight::new(Color::WHITE, LIGHT_POS);
 
     fn world_with_unit_sphere() -> World {
-        let mut world = World::new(LIGHT);
+        let mut world = World::new();
+        world.add_light(LIGHT);
         world.add_shape(Box::new(Sphere::new()));
         world
     }
 
     mod ray_intersections {
-        use crate::shapes::Transform;
+        use crate::shapes::{Group, Material, SmoothTriangle, Transform};
 
         use super::*;
 
@@ -148,7 +360,7 @@ mod tests {
 
         #[test]
         fn empty_world_produces_no_intersections() {
-            let empty_world = World::new(LIGHT);
+            let empty_world = World::new();
 
             let intersections = empty_world.intersect_with(&RAY);
             assert!(intersections.is_empty())
@@ -161,7 +373,7 @@ mod tests {
             world.add_shape(Box::new(smaller_sphere));
 
             let intersections = world.intersect_with(&RAY);
-            let positions: Vec<f64> = intersections.into_iter().map(|i| i.1).collect();
+            let positions: Vec<f64> = intersections.into_iter().map(|i| i.1.t).collect();
             assert_eq!(positions, vec![4., 4.5, 5.5, 6.])
         }
 
@@ -171,7 +383,7 @@ mod tests {
             let ray_from_inside = Ray::new(Point::ZERO, UnitVector::Z);
 
             let intersections = world.intersect_with(&ray_from_inside);
-            let positions: Vec<f64> = intersections.into_iter().map(|i| i.1).collect();
+            let positions: Vec<f64> = intersections.into_iter().map(|i| i.1.t).collect();
             assert_eq!(positions, vec![1.0])
         }
 
@@ -180,19 +392,66 @@ mod tests {
             let world = world_with_unit_sphere();
             let hit = world.hit_with_ray(&RAY).unwrap();
 
-            assert_eq!(hit.shape_index, 0);
+            let shape = world.first_intersection_with(&RAY).unwrap().1.shape;
+            assert!(std::ptr::addr_eq(shape, world.shapes[0].as_ref()));
             assert_eq!(hit.point, Point::new(0., 0., -1.));
             assert_eq!(hit.eye_direction, Vector(0., 0., -1.).normalize());
             assert_eq!(hit.normal, Normal::from(&Vector(0., 0., -1.)));
         }
 
+        #[test]
+        fn hit_normal_is_interpolated_on_smooth_triangle() {
+            let mut world = World::new();
+            world.add_shape(Box::new(SmoothTriangle::new(
+                Point::new(0., 1., 0.),
+                Point::new(-1., 0., 0.),
+                Point::new(1., 0., 0.),
+                Vector(0., 0., -1.),
+                Vector(-1., 0., -1.),
+                Vector(1., 0., -1.),
+            )));
+            let ray = Ray::new(Point::new(-0.2, 0.3, -2.), UnitVector::Z);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert_eq!(hit.point, Point::new(-0.2, 0.3, 0.));
+            assert_eq!(hit.normal, Normal::from(&Vector(-0.2, 0., -1.)));
+        }
+
+        #[test]
+        fn hit_inside_group_uses_child_material_and_parent_transforms() {
+            let red = Material::default_with_color(Color::new(1., 0., 0.));
+            let group = Group::new()
+                .with_transform(Transform::translate(0., 0., 2.))
+                .with_child(Box::new(
+                    Sphere::new()
+                        .with_transform(Transform::scale(2., 2., 2.))
+                        .with_material(red),
+                ));
+            let mut world = World::new();
+            world.add_shape(Box::new(group));
+
+            let hit = world.hit_with_ray(&RAY).unwrap();
+            assert_eq!(hit.point, Point::new(0., 0., 0.));
+            assert_eq!(hit.normal, Normal::from(&Vector(0., 0., -1.)));
+            assert_eq!(hit.object_point, Point::new(0., 0., -1.));
+            assert_eq!(
+                hit.material.color_at(&hit.object_point),
+                Color::new(1., 0., 0.)
+            );
+        }
+
         #[test]
         fn ray_hits_object_from_inside_flips_the_normal() {
             let world = world_with_unit_sphere();
             let ray_from_inside = Ray::new(Point::ZERO, UnitVector::Z);
             let hit = world.hit_with_ray(&ray_from_inside).unwrap();
 
-            assert_eq!(hit.shape_index, 0);
+            let shape = world
+                .first_intersection_with(&ray_from_inside)
+                .unwrap()
+                .1
+                .shape;
+            assert!(std::ptr::addr_eq(shape, world.shapes[0].as_ref()));
             assert_eq!(hit.point, Point::new(0., 0., 1.));
             assert_eq!(hit.eye_direction, Vector(0., 0., -1.).normalize());
             assert_eq!(hit.normal, Normal::from(&Vector(0., 0., -1.)))
@@ -200,16 +459,569 @@ mod tests {
 
         #[test]
         fn cast_ray_hits_closest_object() {
-            let mut world = World::new(LIGHT);
+            let mut world = World::new();
 
             let front_shape = Sphere::new().with_transform(Transform::translate(0., 0., -4.));
             let back_shape = Sphere::new().with_transform(Transform::translate(0., 0., -1.));
 
             world.add_shape(Box::new(back_shape));
             let front_shape_index = world.add_shape(Box::new(front_shape));
+            let hit = world.first_intersection_with(&RAY).unwrap();
+
+            let front_shape = world.shapes[front_shape_index].as_ref();
+            assert!(std::ptr::addr_eq(hit.1.shape, front_shape));
+        }
+    }
+
+    mod bounding_volume_hierarchy {
+        use crate::shapes::{Plane, Transform};
+
+        use super::*;
+
+        fn scattered_spheres(count: usize) -> World {
+            let mut seed: u64 = 42;
+            let mut random = move || {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (seed >> 11) as f64 / (1u64 << 53) as f64
+            };
+
+            let mut world = World::new();
+            world.add_shape(Box::new(
+                Plane::new().with_transform(Transform::translate(0., -10., 0.)),
+            ));
+            for _ in 0..count {
+                let radius = 0.1 + random() * 0.5;
+                let transform =
+                    Transform::scale(radius, radius, radius).and_then(&Transform::translate(
+                        random() * 20. - 10.,
+                        random() * 20. - 10.,
+                        random() * 20. - 10.,
+                    ));
+                world.add_shape(Box::new(Sphere::new().with_transform(transform)));
+            }
+            world
+        }
+
+        #[test]
+        fn closest_hits_match_brute_force_search() {
+            let world = scattered_spheres(300);
+            let origin = Point::new(0., 0., -30.);
+
+            for x in -20..=20 {
+                for y in -20..=20 {
+                    let target = Point::new(x as f64 / 2., y as f64 / 2., 0.);
+                    let ray = Ray::between(&origin, &target);
+
+                    let expected = world.intersect_with(&ray).first().map(|i| (i.0, i.1.t));
+                    let actual = world.first_intersection_with(&ray).map(|i| (i.0, i.1.t));
+                    assert_eq!(actual.map(|(i, _)| i), expected.map(|(i, _)| i));
+                    assert_eq!(
+                        actual.map(|(_, t)| t.to_bits()),
+                        expected.map(|(_, t)| t.to_bits())
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn hierarchy_is_rebuilt_after_adding_shapes() {
+            let mut world = world_with_unit_sphere();
+            let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+            assert_eq!(world.first_intersection_with(&ray).unwrap().0, 0);
+
+            let closer = Sphere::new().with_transform(Transform::translate(0., 0., -3.));
+            let closer_index = world.add_shape(Box::new(closer));
+            assert_eq!(world.first_intersection_with(&ray).unwrap().0, closer_index);
+        }
+    }
+
+    mod rendering {
+        use std::f64::consts::PI;
+
+        use crate::{
+            drawing::Canvas,
+            raycaster::Camera,
+            shapes::{Plane, Shape, Transform},
+        };
+
+        use super::*;
+
+        fn assert_send_and_sync<T: Send + Sync + ?Sized>() {}
+
+        fn pixel_bits(canvas: &Canvas) -> Vec<u64> {
+            canvas
+                .pixels()
+                .iter()
+                .flat_map(|pixel| pixel.to_a())
+                .map(f64::to_bits)
+                .collect()
+        }
+
+        #[test]
+        fn scene_types_can_be_shared_between_threads() {
+            assert_send_and_sync::<World>();
+            assert_send_and_sync::<Camera>();
+            assert_send_and_sync::<dyn Shape>();
+        }
+
+        #[test]
+        fn parallel_render_matches_serial_render() {
+            let mut world = world_with_unit_sphere();
+            world.add_shape(Box::new(
+                Plane::new().with_transform(Transform::translate(0., -1., 0.)),
+            ));
+            let camera = Camera::new(33, 21, PI / 3.).with_transform(
+                &Point::new(0., 1.5, -5.),
+                &Point::ZERO,
+                &Vector(0., 1., 0.),
+            );
+
+            let serial = world.render_with_threads(&camera, 1);
+            let parallel = world.render_with_threads(&camera, 4);
+            assert_eq!(pixel_bits(&parallel), pixel_bits(&serial));
+        }
+    }
+
+    mod lighting {
+        use crate::shapes::Transform;
+
+        use super::*;
+
+        const RAY: Ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+
+        #[test]
+        fn world_without_lights_only_has_black_surfaces() {
+            let mut world = World::new();
+            world.add_shape(Box::new(Sphere::new()));
+
+            assert_eq!(world.get_color(&RAY, 0), Some(Color::BLACK));
+        }
+
+        #[test]
+        fn contributions_of_lights_are_summed() {
+            let mut world = world_with_unit_sphere();
+            let single_light = world.get_color(&RAY, 0).unwrap();
+
+            world.add_light(LIGHT);
+            let two_lights = world.get_color(&RAY, 0).unwrap();
+            assert_eq!(two_lights, single_light * 2.);
+        }
+
+        #[test]
+        fn removed_light_no_longer_contributes() {
+            let mut world = world_with_unit_sphere();
+            let single_light = world.get_color(&RAY, 0).unwrap();
+
+            let fill = PointLight::new(Color::new(0.5, 0.5, 0.5), Point::new(10., 0., -10.));
+            let fill_handle = world.add_light(fill);
+            assert_ne!(world.get_color(&RAY, 0).unwrap(), single_light);
+
+            assert!(world.remove_light(fill_handle).is_some());
+            assert!(world.remove_light(fill_handle).is_none());
+            assert_eq!(world.get_color(&RAY, 0).unwrap(), single_light);
+        }
+
+        #[test]
+        fn each_light_casts_its_own_shadow() {
+            let world = world_with_unit_sphere();
+            let test_point = Point::new(10., -10., 10.);
+            let opposite_light = PointLight::new(Color::WHITE, Point::new(10., 10., 10.));
+
+            assert!(world.is_shadowed(&LIGHT, &test_point));
+            assert!(!world.is_shadowed(&opposite_light, &test_point));
+        }
+
+        #[test]
+        fn surface_is_lit_by_unobstructed_light_only() {
+            let mut world = World::new();
+            world.add_shape(Box::new(Sphere::new()));
+            let blocker = Sphere::new().with_transform(Transform::translate(-3., 0., -3.));
+            world.add_shape(Box::new(blocker));
+
+            let blocked =
+                world.add_light(PointLight::new(Color::WHITE, Point::new(-10., 0., -10.)));
+            let ambient_only = world.get_color(&RAY, 0).unwrap();
+
+            world.remove_light(blocked);
+            world.add_light(PointLight::new(Color::WHITE, Point::new(10., 0., -10.)));
+            let lit = world.get_color(&RAY, 0).unwrap();
+
+            assert_eq!(ambient_only, Color::new(0.1, 0.1, 0.1));
+            assert!(lit.r() > ambient_only.r());
+        }
+    }
+
+    mod patterns {
+        use crate::{
+            patterns::{Marble, Stripes},
+            shapes::{Material, Transform},
+        };
+
+        use super::*;
+
+        const RAY: Ray = Ray::new(Point::new(2.5, 0., -5.), UnitVector::Z);
+
+        fn world_with_sphere(material: Material) -> World {
+            let mut world = World::new();
+            world.add_light(LIGHT);
+            let sphere = Sphere::new()
+                .with_transform(Transform::translate(2.5, 0., 0.))
+                .with_material(material);
+            world.add_shape(Box::new(sphere));
+            world
+        }
+
+        fn striped(pattern_transform: Transform) -> Material {
+            let mut material = Material::default();
+            let stripes =
+                Stripes::new(Color::WHITE, Color::BLACK).with_transform(pattern_transform);
+            material.pattern = Some(Box::new(stripes));
+            material
+        }
+
+        #[test]
+        fn hit_point_is_converted_to_object_space() {
+            let world = world_with_sphere(Material::default());
+
             let hit = world.hit_with_ray(&RAY).unwrap();
+            assert_eq!(hit.object_point, Point::new(0., 0., -1.));
+        }
+
+        #[test]
+        fn pattern_follows_shape_transform() {
+            let patterned = world_with_sphere(striped(Transform::IDENTITY));
+            let solid = world_with_sphere(Material::default_with_color(Color::WHITE));
+
+            assert_eq!(patterned.get_color(&RAY, 0), solid.get_color(&RAY, 0));
+        }
+
+        #[test]
+        fn pattern_transform_is_applied_on_top_of_shape_transform() {
+            let patterned = world_with_sphere(striped(Transform::translate(1., 0., 0.)));
+            let solid = world_with_sphere(Material::default_with_color(Color::BLACK));
+
+            assert_eq!(patterned.get_color(&RAY, 0), solid.get_color(&RAY, 0));
+        }
+
+        #[test]
+        fn solid_texture_moves_with_the_shape() {
+            let marbled = || {
+                let mut material = Material::default();
+                material.pattern = Some(Box::new(Marble::new(Color::WHITE, Color::BLACK)));
+                material
+            };
+            let moved = world_with_sphere(marbled());
+            let mut origin = World::new();
+            origin.add_light(PointLight::new(
+                Color::WHITE,
+                LIGHT_POS - Vector(2.5, 0., 0.),
+            ));
+            origin.add_shape(Box::new(Sphere::new().with_material(marbled())));
+
+            let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+            assert_eq!(moved.get_color(&RAY, 0), origin.get_color(&ray, 0));
+        }
+    }
+
+    mod reflections {
+        use std::f64::consts::SQRT_2;
+
+        use crate::shapes::{Material, Plane, Transform};
+
+        use super::*;
+
+        fn reflective_floor(reflective: f64) -> Plane {
+            let mut material = Material::default();
+            material.reflective = reflective;
+            Plane::new()
+                .with_material(material)
+                .with_transform(Transform::translate(0., -1., 0.))
+        }
+
+        #[test]
+        fn reflected_direction_mirrors_the_ray() {
+            let mut world = World::new();
+            world.add_shape(Box::new(reflective_floor(0.5)));
+            let ray = Ray::new(Point::new(0., 1., -1.), Vector(0., -1., 1.).normalize());
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert_eq!(hit.reflect_direction, Vector(0., 1., 1.).normalize());
+        }
+
+        #[test]
+        fn non_reflective_surface_reflects_nothing() {
+            let world = world_with_unit_sphere();
+            let hit = world.hit_with_ray(&Ray::new(Point::new(0., 0., -5.), UnitVector::Z));
+
+            assert_eq!(world.reflected_color(&hit.unwrap(), 5), Color::BLACK);
+        }
+
+        #[test]
+        fn reflective_surface_reflects_other_objects() {
+            let mut world = world_with_unit_sphere();
+            world.add_shape(Box::new(reflective_floor(0.5)));
+            let direction = Vector(0., -SQRT_2 / 2., SQRT_2 / 2.).normalize();
+            let ray = Ray::new(Point::new(0., 0., -3.), direction);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert_eq!(hit.point, Point::new(0., -1., -2.));
+
+            let sphere_ray = Ray::new(hit.over_point, hit.reflect_direction);
+            let sphere_color = world.get_color(&sphere_ray, 0).unwrap();
+            assert_eq!(world.reflected_color(&hit, 1), &sphere_color * 0.5);
+            assert_ne!(sphere_color, Color::BLACK);
+        }
+
+        #[test]
+        fn shaded_color_includes_reflection() {
+            let mut world = world_with_unit_sphere();
+            world.add_shape(Box::new(reflective_floor(0.5)));
+            let direction = Vector(0., -SQRT_2 / 2., SQRT_2 / 2.).normalize();
+            let ray = Ray::new(Point::new(0., 0., -3.), direction);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            let without_reflection = world.shade_hit(&hit, 0);
+            let with_reflection = world.shade_hit(&hit, 1);
+            assert_eq!(
+                with_reflection,
+                without_reflection + world.reflected_color(&hit, 1)
+            );
+        }
+
+        #[test]
+        fn reflection_stops_at_maximum_depth() {
+            let mut world = world_with_unit_sphere();
+            world.add_shape(Box::new(reflective_floor(0.5)));
+            let direction = Vector(0., -SQRT_2 / 2., SQRT_2 / 2.).normalize();
+            let hit = world
+                .hit_with_ray(&Ray::new(Point::new(0., 0., -3.), direction))
+                .unwrap();
+
+            assert_eq!(world.reflected_color(&hit, 0), Color::BLACK);
+        }
+
+        #[test]
+        fn mutually_reflective_surfaces_terminate() {
+            let mut world = World::new();
+            world.add_light(PointLight::new(Color::WHITE, Point::ZERO));
+            world.add_shape(Box::new(reflective_floor(1.)));
+            let ceiling = reflective_floor(1.).with_transform(Transform::translate(0., 1., 0.));
+            world.add_shape(Box::new(ceiling));
+            world.set_max_depth(10);
+
+            let ray = Ray::new(Point::ZERO, UnitVector::Y);
+            let color = world.get_color(&ray, world.max_depth);
+            assert!(color.is_some());
+        }
+    }
+
+    mod refractions {
+        use std::{f64::consts::SQRT_2, sync::Arc};
+
+        use crate::{
+            raycaster::world::ShapeIntersection,
+            shapes::{Instance, Material, Plane, Shape, Transform},
+        };
+
+        use super::*;
+
+        fn glass_material(refractive_index: f64) -> Material {
+            let mut material = Material::default();
+            material.transparency = 1.;
+            material.refractive_index = refractive_index;
+            material
+        }
+
+        fn glass_sphere() -> Sphere {
+            Sphere::new().with_material(glass_material(1.5))
+        }
+
+        #[test]
+        fn refractive_indices_at_nested_glass_spheres() {
+            let mut world = World::new();
+            let a = Sphere::new()
+                .with_material(glass_material(1.5))
+                .with_transform(Transform::scale(2., 2., 2.));
+            let b = Sphere::new()
+                .with_material(glass_material(2.))
+                .with_transform(Transform::translate(0., 0., -0.25));
+            let c = Sphere::new()
+                .with_material(glass_material(2.5))
+                .with_transform(Transform::translate(0., 0., 0.25));
+            let a = world.add_shape(Box::new(a));
+            let b = world.add_shape(Box::new(b));
+            let c = world.add_shape(Box::new(c));
+            let ray = Ray::new(Point::new(0., 0., -4.), UnitVector::Z);
+
+            let expectations = [
+                (a, 2., (1., 1.5)),
+                (b, 2.75, (1.5, 2.)),
+                (c, 3.25, (2., 2.5)),
+                (b, 4.75, (2.5, 2.5)),
+                (c, 5.25, (2.5, 1.5)),
+                (a, 6., (1.5, 1.)),
+            ];
+            for (index, distance, indices) in expectations {
+                let xs = world.shapes[index].intersect_with(&ray);
+                let hit = xs.into_iter().find(|x| x.t == distance).unwrap();
+                let hit = ShapeIntersection(index, hit);
+                assert_eq!(world.refractive_indices(&ray, &hit), indices);
+            }
+        }
+
+        #[test]
+        fn instances_of_one_geometry_are_separate_media() {
+            let mut world = World::new();
+            world.add_shape(Box::new(
+                Sphere::new()
+                    .with_material(glass_material(1.5))
+                    .with_transform(Transform::scale(2., 2., 2.)),
+            ));
+            let geometry: Arc<dyn Shape> = Arc::new(glass_sphere());
+            for (z, refractive_index) in [(-0.25, 2.), (0.25, 2.5)] {
+                let instance = Instance::new(geometry.clone())
+                    .with_transform(Transform::translate(0., 0., z))
+                    .with_material(glass_material(refractive_index));
+                world.add_shape(Box::new(instance));
+            }
+            let ray = Ray::new(Point::new(0., 0., -4.), UnitVector::Z);
+
+            let indices: Vec<(f64, f64)> = world
+                .intersect_with(&ray)
+                .iter()
+                .map(|hit| world.refractive_indices(&ray, hit))
+                .collect();
+            assert_eq!(
+                indices,
+                vec![
+                    (1., 1.5),
+                    (1.5, 2.),
+                    (2., 2.5),
+                    (2.5, 2.5),
+                    (2.5, 1.5),
+                    (1.5, 1.)
+                ]
+            );
+        }
+
+        #[test]
+        fn under_point_is_below_the_surface() {
+            let mut world = World::new();
+            world.add_shape(Box::new(
+                glass_sphere().with_transform(Transform::translate(0., 0., 1.)),
+            ));
+            let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert!(hit.under_point.z() > hit.point.z());
+            assert_eq!((hit.n1, hit.n2), (1., 1.5));
+        }
+
+        #[test]
+        fn opaque_surface_refracts_nothing() {
+            let world = world_with_unit_sphere();
+            let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert_eq!(world.refracted_color(&hit, 5), Color::BLACK);
+        }
+
+        #[test]
+        fn refraction_stops_at_maximum_depth() {
+            let mut world = world_with_unit_sphere();
+            world.shapes[0] = Box::new(glass_sphere());
+            let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert_eq!(world.refracted_color(&hit, 0), Color::BLACK);
+        }
+
+        #[test]
+        fn total_internal_reflection_refracts_nothing() {
+            let mut world = world_with_unit_sphere();
+            world.shapes[0] = Box::new(glass_sphere());
+            let ray = Ray::new(Point::new(0., 0., SQRT_2 / 2.), UnitVector::Y);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert_eq!((hit.n1, hit.n2), (1.5, 1.));
+            assert_eq!(world.refracted_color(&hit, 5), Color::BLACK);
+        }
+
+        #[test]
+        fn transparent_floor_shows_objects_below() {
+            let mut world = world_with_unit_sphere();
+            let floor = Plane::new()
+                .with_material(glass_material(1.5))
+                .with_transform(Transform::translate(0., -1., 0.));
+            world.add_shape(Box::new(floor));
+            let ball = Sphere::new()
+                .with_material(Material::default_with_color(Color::new(1., 0., 0.)))
+                .with_transform(Transform::translate(0., -3.5, -0.5));
+            world.add_shape(Box::new(ball));
+            let direction = Vector(0., -SQRT_2 / 2., SQRT_2 / 2.).normalize();
+            let ray = Ray::new(Point::new(0., 0., -3.), direction);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            let refracted = world.refracted_color(&hit, 5);
+            assert!(refracted.r() > 0.);
+            assert_eq!((refracted.g(), refracted.b()), (0., 0.));
+            assert_eq!(
+                world.shade_hit(&hit, 5),
+                world.shade_hit(&hit, 0) + refracted
+            );
+        }
+
+        #[test]
+        fn schlick_approximation_under_total_internal_reflection() {
+            let mut world = World::new();
+            world.add_shape(Box::new(glass_sphere()));
+            let ray = Ray::new(Point::new(0., 0., SQRT_2 / 2.), UnitVector::Y);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert_eq!(hit.schlick(), 1.);
+        }
+
+        #[test]
+        fn schlick_approximation_with_perpendicular_ray() {
+            let mut world = World::new();
+            world.add_shape(Box::new(glass_sphere()));
+            let ray = Ray::new(Point::ZERO, UnitVector::Y);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert!((hit.schlick() - 0.04).abs() < 1e-5);
+        }
+
+        #[test]
+        fn schlick_approximation_with_small_angle() {
+            let mut world = World::new();
+            world.add_shape(Box::new(glass_sphere()));
+            let ray = Ray::new(Point::new(0., 0.99, -2.), UnitVector::Z);
+
+            let hit = world.hit_with_ray(&ray).unwrap();
+            assert!((hit.schlick() - 0.48881).abs() < 1e-5);
+        }
+
+        #[test]
+        fn reflective_transparent_surface_blends_with_schlick() {
+            let mut world = world_with_unit_sphere();
+            let mut material = glass_material(1.5);
+            material.reflective = 0.5;
+            material.transparency = 0.5;
+            let floor = Plane::new()
+                .with_material(material)
+                .with_transform(Transform::translate(0., -1., 0.));
+            world.add_shape(Box::new(floor));
+            let direction = Vector(0., -SQRT_2 / 2., SQRT_2 / 2.).normalize();
+            let ray = Ray::new(Point::new(0., 0., -3.), direction);
 
-            assert_eq!(hit.shape_index, front_shape_index);
+            let hit = world.hit_with_ray(&ray).unwrap();
+            let reflectance = hit.schlick();
+            let expected = world.shade_hit(&hit, 0)
+                + world.reflected_color(&hit, 5) * reflectance
+                + world.refracted_color(&hit, 5) * (1. - reflectance);
+            assert_eq!(world.shade_hit(&hit, 5), expected);
         }
     }
 
@@ -221,7 +1033,7 @@ mod tests {
             let world = world_with_unit_sphere();
             let test_point = Point::new(0., 10., 0.);
 
-            assert!(!world.is_shadowed(&test_point));
+            assert!(!world.is_shadowed(&LIGHT, &test_point));
         }
 
         #[test]
@@ -229,7 +1041,7 @@ mod tests {
             let world = world_with_unit_sphere();
             let test_point = Point::new(-20., 20., -20.);
 
-            assert!(!world.is_shadowed(&test_point));
+            assert!(!world.is_shadowed(&LIGHT, &test_point));
         }
 
         #[test]
@@ -237,7 +1049,7 @@ mod tests {
             let world = world_with_unit_sphere();
             let test_point = Point::new(-20., 20., -20.);
 
-            assert!(!world.is_shadowed(&test_point));
+            assert!(!world.is_shadowed(&LIGHT, &test_point));
         }
 
         #[test]
@@ -245,7 +1057,7 @@ mod tests {
             let world = world_with_unit_sphere();
             let test_point = Point::new(10., -10., 10.);
 
-            assert!(world.is_shadowed(&test_point));
+            assert!(world.is_shadowed(&LIGHT, &test_point));
         }
     }
 }
@@ -0,0 +1,212 @@
+use crate::geometry::{solve_quadratic_equation, Aabb, Point, Ray, Vector};
+
+use super::{cylinder::within_radius, Intersection, Material, Shape, Surface, Transform};
+
+pub struct Cone {
+    material: Material,
+    transform: Transform,
+    minimum: f64,
+    maximum: f64,
+    closed: bool,
+}
+
+impl Cone {
+    const EPSILON: f64 = 1.0e-8;
+
+    pub const fn new() -> Cone {
+        Cone {
+            material: Material::default(),
+            transform: Transform::IDENTITY,
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn with_limits(mut self, minimum: f64, maximum: f64) -> Self {
+        self.minimum = minimum;
+        self.maximum = maximum;
+        self
+    }
+
+    pub fn with_caps(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    fn intersect_caps(&self, ray: &Ray, xs: &mut Vec<f64>) {
+        if !self.closed || ray.direction.1.abs() < Self::EPSILON {
+            return;
+        }
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin.y()) / ray.direction.1;
+            if within_radius(ray, t, y.abs()) {
+                xs.push(t);
+            }
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shape for Cone {
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let (o, d) = (&ray.origin, &ray.direction);
+        let a = d.0 * d.0 - d.1 * d.1 + d.2 * d.2;
+        let b = 2. * (o.x() * d.0 - o.y() * d.1 + o.z() * d.2);
+        let c = o.x() * o.x() - o.y() * o.y() + o.z() * o.z();
+
+        let mut xs: Vec<f64> = solve_quadratic_equation(a, b, c)
+            .into_iter()
+            .filter(|&t| {
+                let y = o.y() + t * d.1;
+                self.minimum < y && y < self.maximum
+            })
+            .collect();
+        self.intersect_caps(ray, &mut xs);
+        xs.sort_by(f64::total_cmp);
+        xs.into_iter().map(|t| Intersection::new(t, self)).collect()
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        let radius = self.minimum.abs().max(self.maximum.abs());
+        Aabb::new(
+            Point::new(-radius, self.minimum, -radius),
+            Point::new(radius, self.maximum, radius),
+        )
+    }
+}
+
+impl Surface for Cone {
+    fn local_normal_at(&self, pt: &Point, _hit: &Intersection) -> Vector {
+        let distance = pt.x() * pt.x() + pt.z() * pt.z();
+        let radius = pt.y() * pt.y();
+        if distance < radius && pt.y() >= self.maximum - Self::EPSILON {
+            Vector(0., 1., 0.)
+        } else if distance < radius && pt.y() <= self.minimum + Self::EPSILON {
+            Vector(0., -1., 0.)
+        } else {
+            let y = distance.sqrt();
+            Vector(pt.x(), if pt.y() > 0. { -y } else { y }, pt.z())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::SQRT_2;
+
+    use crate::geometry::Normal;
+
+    use super::*;
+
+    const CONE: Cone = Cone::new();
+
+    fn intersect(cone: &Cone, origin: Point, direction: Vector) -> Vec<f64> {
+        cone.intersect_with(&Ray::new(origin, direction.normalize()))
+            .iter()
+            .map(|x| x.t)
+            .collect()
+    }
+
+    fn assert_approx(actual: Vec<f64>, expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len(), "intersections {actual:?}");
+        for (t, expected) in actual.iter().zip(expected) {
+            assert!((t - expected).abs() < 1e-5, "intersections {actual:?}");
+        }
+    }
+
+    #[test]
+    pub fn ray_strikes_double_napped_cone() {
+        assert_approx(
+            intersect(&CONE, Point::new(0., 0., -5.), Vector(0., 0., 1.)),
+            &[5., 5.],
+        );
+        assert_approx(
+            intersect(&CONE, Point::new(0., 0., -5.), Vector(1., 1., 1.)),
+            &[8.66025, 8.66025],
+        );
+        assert_approx(
+            intersect(&CONE, Point::new(1., 1., -5.), Vector(-0.5, -1., 1.)),
+            &[4.55006, 49.44994],
+        );
+    }
+
+    #[test]
+    pub fn ray_parallel_to_one_half() {
+        assert_approx(
+            intersect(&CONE, Point::new(0., 0., -1.), Vector(0., 1., 1.)),
+            &[SQRT_2 / 2.],
+        );
+    }
+
+    #[test]
+    pub fn closed_cone_caps() {
+        let cone = Cone::new().with_limits(-0.5, 0.5).with_caps(true);
+        let cases = [
+            (Point::new(0., 0., -5.), Vector(0., 1., 0.), 0),
+            (Point::new(0., 0., -0.25), Vector(0., 1., 1.), 2),
+            (Point::new(0., 0., -0.25), Vector(0., 1., 0.), 4),
+        ];
+        for (origin, direction, count) in cases {
+            assert_eq!(intersect(&cone, origin, direction).len(), count);
+        }
+    }
+
+    #[test]
+    pub fn normal_on_side() {
+        assert_eq!(
+            CONE.normal_at(&Point::new(1., 1., 1.), &Intersection::new(0., &CONE)),
+            Normal::from(&Vector(1., -SQRT_2, 1.))
+        );
+        assert_eq!(
+            CONE.normal_at(&Point::new(-1., -1., 0.), &Intersection::new(0., &CONE)),
+            Normal::new(-1., 1., 0.)
+        );
+    }
+
+    #[test]
+    pub fn normal_on_caps() {
+        let cone = Cone::new().with_limits(-1., 2.).with_caps(true);
+
+        assert_eq!(
+            cone.normal_at(&Point::new(0.5, 2., 0.), &Intersection::new(0., &cone)),
+            Normal::new(0., 1., 0.)
+        );
+        assert_eq!(
+            cone.normal_at(&Point::new(0., -1., 0.5), &Intersection::new(0., &cone)),
+            Normal::new(0., -1., 0.)
+        );
+    }
+
+    #[test]
+    pub fn bounds_use_widest_end() {
+        assert!(!CONE.bounds().is_finite());
+        assert_eq!(
+            Cone::new().with_limits(-1., 3.).bounds(),
+            Aabb::new(Point::new(-3., -1., -3.), Point::new(3., 3., 3.))
+        );
+    }
+}
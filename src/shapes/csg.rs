@@ -0,0 +1,271 @@
+use crate::geometry::{Aabb, Ray};
+
+use super::{material::DEFAULT_MATERIAL, Intersection, Material, Shape, Transform};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CsgOperation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl CsgOperation {
+    fn allows(&self, left_hit: bool, in_left: bool, in_right: bool) -> bool {
+        match self {
+            CsgOperation::Union => (left_hit && !in_right) || (!left_hit && !in_left),
+            CsgOperation::Intersection => (left_hit && in_right) || (!left_hit && in_left),
+            CsgOperation::Difference => (left_hit && !in_right) || (!left_hit && in_left),
+        }
+    }
+}
+
+pub struct Csg {
+    material: Option<Material>,
+    transform: Transform,
+    operation: CsgOperation,
+    left: Box<dyn Shape>,
+    right: Box<dyn Shape>,
+    bounds: Aabb,
+}
+
+impl Csg {
+    pub fn new(operation: CsgOperation, left: Box<dyn Shape>, right: Box<dyn Shape>) -> Csg {
+        Csg {
+            material: None,
+            transform: Transform::IDENTITY,
+            operation,
+            bounds: left.bounds().union(&right.bounds()),
+            left,
+            right,
+        }
+    }
+
+    pub fn union(left: Box<dyn Shape>, right: Box<dyn Shape>) -> Csg {
+        Self::new(CsgOperation::Union, left, right)
+    }
+
+    pub fn intersection(left: Box<dyn Shape>, right: Box<dyn Shape>) -> Csg {
+        Self::new(CsgOperation::Intersection, left, right)
+    }
+
+    pub fn difference(left: Box<dyn Shape>, right: Box<dyn Shape>) -> Csg {
+        Self::new(CsgOperation::Difference, left, right)
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = Some(material);
+        self
+    }
+
+    fn filter_intersections<'a>(&self, xs: Vec<(bool, Intersection<'a>)>) -> Vec<Intersection<'a>> {
+        let mut in_left = false;
+        let mut in_right = false;
+
+        xs.into_iter()
+            .filter(|&(left_hit, _)| {
+                let allowed = self.operation.allows(left_hit, in_left, in_right);
+                if left_hit {
+                    in_left = !in_left;
+                } else {
+                    in_right = !in_right;
+                }
+                allowed
+            })
+            .map(|(_, hit)| hit)
+            .collect()
+    }
+}
+
+impl Shape for Csg {
+    fn material(&self) -> &Material {
+        self.material.as_ref().unwrap_or(&DEFAULT_MATERIAL)
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        if self.bounds.intersect_with(ray).is_none() {
+            return vec![];
+        }
+        let left = self
+            .left
+            .intersect_with(ray)
+            .into_iter()
+            .map(|hit| (true, hit));
+        let right = self
+            .right
+            .intersect_with(ray)
+            .into_iter()
+            .map(|hit| (false, hit));
+        let mut xs: Vec<(bool, Intersection)> = left
+            .chain(right)
+            .map(|(left_hit, mut hit)| {
+                hit.parents.push(&self.transform);
+                if let Some(material) = &self.material {
+                    hit.material_override = Some(material);
+                }
+                (left_hit, hit)
+            })
+            .collect();
+        xs.sort_by(|a, b| a.1.t.total_cmp(&b.1.t));
+        self.filter_intersections(xs)
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        self.bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        drawing::Color,
+        geometry::{Point, UnitVector, Vector},
+        shapes::{Cube, Group, Sphere},
+    };
+
+    use super::*;
+
+    #[test]
+    pub fn operation_rules() {
+        use CsgOperation::*;
+        let cases = [
+            (Union, [false, true, false, true, false, false, true, true]),
+            (
+                Intersection,
+                [true, false, true, false, true, true, false, false],
+            ),
+            (
+                Difference,
+                [false, true, false, true, true, true, false, false],
+            ),
+        ];
+        for (operation, expected) in cases {
+            let mut actual = vec![];
+            for left_hit in [true, false] {
+                for in_left in [true, false] {
+                    for in_right in [true, false] {
+                        actual.push(operation.allows(left_hit, in_left, in_right));
+                    }
+                }
+            }
+            assert_eq!(actual, expected, "{:?}", operation);
+        }
+    }
+
+    #[test]
+    pub fn filter_intersections_by_operation() {
+        let cases = [
+            (CsgOperation::Union, vec![0, 3]),
+            (CsgOperation::Intersection, vec![1, 2]),
+            (CsgOperation::Difference, vec![0, 1]),
+        ];
+        for (operation, expected) in cases {
+            let csg = Csg::new(operation, Box::new(Sphere::new()), Box::new(Cube::new()));
+            let (sphere, cube) = (Sphere::new(), Cube::new());
+            let xs = vec![
+                (true, Intersection::new(1., &sphere)),
+                (false, Intersection::new(2., &cube)),
+                (true, Intersection::new(3., &sphere)),
+                (false, Intersection::new(4., &cube)),
+            ];
+            let expected: Vec<f64> = expected.iter().map(|&i| xs[i].1.t).collect();
+
+            let result = csg.filter_intersections(xs);
+            assert_eq!(result.iter().map(|x| x.t).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    pub fn ray_misses_csg_object() {
+        let csg = Csg::union(Box::new(Sphere::new()), Box::new(Cube::new()));
+        let ray = Ray::new(Point::new(0., 2., -5.), UnitVector::Z);
+        assert!(csg.intersect_with(&ray).is_empty());
+    }
+
+    #[test]
+    pub fn ray_hits_csg_object() {
+        let csg = Csg::union(
+            Box::new(Sphere::new()),
+            Box::new(Sphere::new().with_transform(Transform::translate(0., 0., 0.5))),
+        );
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+
+        let xs = csg.intersect_with(&ray);
+        assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<_>>(), vec![4., 6.5]);
+        assert!(std::ptr::addr_eq(xs[0].shape, csg.left.as_ref()));
+        assert!(std::ptr::addr_eq(xs[1].shape, csg.right.as_ref()));
+    }
+
+    #[test]
+    pub fn difference_carves_out_the_right_shape() {
+        let csg = Csg::difference(
+            Box::new(Cube::new()),
+            Box::new(Sphere::new().with_transform(Transform::scale(0.5, 0.5, 2.))),
+        );
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+        assert!(csg.intersect_with(&ray).is_empty());
+
+        let ray = Ray::new(Point::new(0.75, 0., -5.), UnitVector::Z);
+        let xs = csg.intersect_with(&ray);
+        assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<_>>(), vec![4., 6.]);
+    }
+
+    #[test]
+    pub fn children_inside_groups_are_attributed_to_their_side() {
+        let left = Group::new().with_child(Box::new(Sphere::new()));
+        let right = Group::new().with_child(Box::new(
+            Sphere::new().with_transform(Transform::translate(0., 0., 0.5)),
+        ));
+        let csg = Csg::intersection(Box::new(left), Box::new(right));
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+
+        let xs = csg.intersect_with(&ray);
+        assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<_>>(), vec![4.5, 6.]);
+        assert_eq!(
+            xs[0].normal_at(&Point::new(0., 0., -0.5)).v(),
+            Vector(0., 0., -1.)
+        );
+    }
+
+    #[test]
+    pub fn transform_applies_to_both_children() {
+        let csg = Csg::union(Box::new(Sphere::new()), Box::new(Cube::new()))
+            .with_transform(Transform::translate(0., 0., 2.));
+
+        assert_eq!(
+            csg.bounds(),
+            Aabb::new(Point::new(-1., -1., 1.), Point::new(1., 1., 3.))
+        );
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+        let xs = csg.intersect_with(&ray);
+        assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<_>>(), vec![6., 8.]);
+        assert_eq!(
+            xs[0].world_to_object(&Point::new(0., 0., 1.)),
+            Point::new(0., 0., -1.)
+        );
+    }
+
+    #[test]
+    pub fn csg_material_applies_to_both_children() {
+        let red = Material::default_with_color(Color::new(1., 0., 0.));
+        let csg = Csg::union(
+            Box::new(Sphere::new()),
+            Box::new(Sphere::new().with_transform(Transform::translate(0., 0., 0.5))),
+        )
+        .with_material(red);
+
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+        let xs = csg.intersect_with(&ray);
+        assert!(xs
+            .iter()
+            .all(|x| x.material().color == Color::new(1., 0., 0.)));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::geometry::{Aabb, Point, Ray, Vector};
+
+use super::{Intersection, Material, Shape, Surface, Transform};
+
+pub struct Cube {
+    material: Material,
+    transform: Transform,
+}
+
+impl Cube {
+    const EPSILON: f64 = 1.0e-8;
+
+    pub const fn new() -> Cube {
+        Cube {
+            material: Material::default(),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    fn check_axis(origin: f64, direction: f64) -> (f64, f64) {
+        let tmin_numerator = -1. - origin;
+        let tmax_numerator = 1. - origin;
+
+        let (tmin, tmax) = if direction.abs() >= Self::EPSILON {
+            (tmin_numerator / direction, tmax_numerator / direction)
+        } else {
+            (
+                tmin_numerator * f64::INFINITY,
+                tmax_numerator * f64::INFINITY,
+            )
+        };
+        if tmin > tmax {
+            (tmax, tmin)
+        } else {
+            (tmin, tmax)
+        }
+    }
+}
+
+impl Default for Cube {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shape for Cube {
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let (xtmin, xtmax) = Self::check_axis(ray.origin.x(), ray.direction.0);
+        let (ytmin, ytmax) = Self::check_axis(ray.origin.y(), ray.direction.1);
+        let (ztmin, ztmax) = Self::check_axis(ray.origin.z(), ray.direction.2);
+
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+        if tmin > tmax {
+            vec![]
+        } else {
+            vec![Intersection::new(tmin, self), Intersection::new(tmax, self)]
+        }
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        Aabb::new(Point::new(-1., -1., -1.), Point::new(1., 1., 1.))
+    }
+}
+
+impl Surface for Cube {
+    fn local_normal_at(&self, pt: &Point, _hit: &Intersection) -> Vector {
+        let (x, y, z) = (pt.x().abs(), pt.y().abs(), pt.z().abs());
+        let max = x.max(y).max(z);
+        if max == x {
+            Vector(pt.x(), 0., 0.)
+        } else if max == y {
+            Vector(0., pt.y(), 0.)
+        } else {
+            Vector(0., 0., pt.z())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::geometry::{Normal, UnitVector};
+
+    use super::*;
+
+    const CUBE: Cube = Cube::new();
+
+    fn intersect(origin: Point, direction: Vector) -> Vec<f64> {
+        CUBE.intersect_with(&Ray::new(origin, direction.normalize()))
+            .iter()
+            .map(|x| x.t)
+            .collect()
+    }
+
+    #[test]
+    pub fn ray_hits_each_face() {
+        let cases = [
+            (Point::new(5., 0.5, 0.), Vector(-1., 0., 0.)),
+            (Point::new(-5., 0.5, 0.), Vector(1., 0., 0.)),
+            (Point::new(0.5, 5., 0.), Vector(0., -1., 0.)),
+            (Point::new(0.5, -5., 0.), Vector(0., 1., 0.)),
+            (Point::new(0.5, 0., 5.), Vector(0., 0., -1.)),
+            (Point::new(0.5, 0., -5.), Vector(0., 0., 1.)),
+        ];
+        for (origin, direction) in cases {
+            assert_eq!(intersect(origin, direction), vec![4., 6.]);
+        }
+    }
+
+    #[test]
+    pub fn ray_originates_inside_cube() {
+        assert_eq!(
+            intersect(Point::new(0., 0.5, 0.), Vector(0., 0., 1.)),
+            vec![-1., 1.]
+        );
+    }
+
+    #[test]
+    pub fn ray_misses_cube() {
+        let cases = [
+            (Point::new(-2., 0., 0.), Vector(0.2673, 0.5345, 0.8018)),
+            (Point::new(0., -2., 0.), Vector(0.8018, 0.2673, 0.5345)),
+            (Point::new(0., 0., -2.), Vector(0.5345, 0.8018, 0.2673)),
+            (Point::new(2., 0., 2.), Vector(0., 0., -1.)),
+            (Point::new(0., 2., 2.), Vector(0., -1., 0.)),
+            (Point::new(2., 2., 0.), Vector(-1., 0., 0.)),
+        ];
+        for (origin, direction) in cases {
+            assert!(intersect(origin, direction).is_empty());
+        }
+    }
+
+    #[test]
+    pub fn normal_points_out_of_each_face() {
+        let cases = [
+            (Point::new(1., 0.5, -0.8), Normal::new(1., 0., 0.)),
+            (Point::new(-1., -0.2, 0.9), Normal::new(-1., 0., 0.)),
+            (Point::new(-0.4, 1., -0.1), Normal::new(0., 1., 0.)),
+            (Point::new(0.3, -1., -0.7), Normal::new(0., -1., 0.)),
+            (Point::new(-0.6, 0.3, 1.), Normal::new(0., 0., 1.)),
+            (Point::new(0.4, 0.4, -1.), Normal::new(0., 0., -1.)),
+            (Point::new(1., 1., 1.), Normal::new(1., 0., 0.)),
+            (Point::new(-1., -1., -1.), Normal::new(-1., 0., 0.)),
+        ];
+        for (pt, normal) in cases {
+            assert_eq!(CUBE.normal_at(&pt, &Intersection::new(0., &CUBE)), normal);
+        }
+    }
+
+    #[test]
+    pub fn intersect_transformed_cube() {
+        let cube = Cube::new()
+            .with_transform(Transform::scale(2., 1., 1.).and_then(&Transform::rotate_y(PI / 2.)));
+
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+        assert_eq!(
+            cube.intersect_with(&ray)
+                .iter()
+                .map(|x| x.t)
+                .collect::<Vec<_>>(),
+            vec![3., 7.]
+        );
+        assert_eq!(
+            cube.normal_at(&Point::new(0., 0., -2.), &Intersection::new(0., &cube)),
+            Normal::new(0., 0., -1.)
+        );
+    }
+
+    #[test]
+    pub fn bounds_of_translated_cube() {
+        let cube = Cube::new().with_transform(Transform::translate(1., 2., 3.));
+
+        assert_eq!(
+            cube.bounds(),
+            Aabb::new(Point::new(0., 1., 2.), Point::new(2., 3., 4.))
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use crate::geometry::{solve_quadratic_equation, Aabb, Point, Ray, Vector};
+
+use super::{Intersection, Material, Shape, Surface, Transform};
+
+pub struct Cylinder {
+    material: Material,
+    transform: Transform,
+    minimum: f64,
+    maximum: f64,
+    closed: bool,
+}
+
+impl Cylinder {
+    const EPSILON: f64 = 1.0e-8;
+
+    pub const fn new() -> Cylinder {
+        Cylinder {
+            material: Material::default(),
+            transform: Transform::IDENTITY,
+            minimum: f64::NEG_INFINITY,
+            maximum: f64::INFINITY,
+            closed: false,
+        }
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn with_limits(mut self, minimum: f64, maximum: f64) -> Self {
+        self.minimum = minimum;
+        self.maximum = maximum;
+        self
+    }
+
+    pub fn with_caps(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+
+    fn intersect_caps(&self, ray: &Ray, xs: &mut Vec<f64>) {
+        if !self.closed || ray.direction.1.abs() < Self::EPSILON {
+            return;
+        }
+        for y in [self.minimum, self.maximum] {
+            let t = (y - ray.origin.y()) / ray.direction.1;
+            if within_radius(ray, t, 1.) {
+                xs.push(t);
+            }
+        }
+    }
+}
+
+pub(super) fn within_radius(ray: &Ray, t: f64, radius: f64) -> bool {
+    let x = ray.origin.x() + t * ray.direction.0;
+    let z = ray.origin.z() + t * ray.direction.2;
+    x * x + z * z <= radius * radius
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shape for Cylinder {
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let (o, d) = (&ray.origin, &ray.direction);
+        let a = d.0 * d.0 + d.2 * d.2;
+        let b = 2. * (o.x() * d.0 + o.z() * d.2);
+        let c = o.x() * o.x() + o.z() * o.z() - 1.;
+
+        let mut xs: Vec<f64> = solve_quadratic_equation(a, b, c)
+            .into_iter()
+            .filter(|&t| {
+                let y = o.y() + t * d.1;
+                self.minimum < y && y < self.maximum
+            })
+            .collect();
+        self.intersect_caps(ray, &mut xs);
+        xs.sort_by(f64::total_cmp);
+        xs.into_iter().map(|t| Intersection::new(t, self)).collect()
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        Aabb::new(
+            Point::new(-1., self.minimum, -1.),
+            Point::new(1., self.maximum, 1.),
+        )
+    }
+}
+
+impl Surface for Cylinder {
+    fn local_normal_at(&self, pt: &Point, _hit: &Intersection) -> Vector {
+        let distance = pt.x() * pt.x() + pt.z() * pt.z();
+        if distance < 1. && pt.y() >= self.maximum - Self::EPSILON {
+            Vector(0., 1., 0.)
+        } else if distance < 1. && pt.y() <= self.minimum + Self::EPSILON {
+            Vector(0., -1., 0.)
+        } else {
+            Vector(pt.x(), 0., pt.z())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Normal;
+
+    use super::*;
+
+    const CYLINDER: Cylinder = Cylinder::new();
+
+    fn intersect(cylinder: &Cylinder, origin: Point, direction: Vector) -> Vec<f64> {
+        cylinder
+            .intersect_with(&Ray::new(origin, direction.normalize()))
+            .iter()
+            .map(|x| x.t)
+            .collect()
+    }
+
+    fn assert_approx(actual: Vec<f64>, expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len(), "intersections {actual:?}");
+        for (t, expected) in actual.iter().zip(expected) {
+            assert!((t - expected).abs() < 1e-5, "intersections {actual:?}");
+        }
+    }
+
+    #[test]
+    pub fn ray_misses_cylinder() {
+        let cases = [
+            (Point::new(1., 0., 0.), Vector(0., 1., 0.)),
+            (Point::new(0., 0., 0.), Vector(0., 1., 0.)),
+            (Point::new(0., 0., -5.), Vector(1., 1., 1.)),
+        ];
+        for (origin, direction) in cases {
+            assert!(intersect(&CYLINDER, origin, direction).is_empty());
+        }
+    }
+
+    #[test]
+    pub fn ray_strikes_cylinder() {
+        assert_approx(
+            intersect(&CYLINDER, Point::new(1., 0., -5.), Vector(0., 0., 1.)),
+            &[5., 5.],
+        );
+        assert_approx(
+            intersect(&CYLINDER, Point::new(0., 0., -5.), Vector(0., 0., 1.)),
+            &[4., 6.],
+        );
+        assert_approx(
+            intersect(&CYLINDER, Point::new(0.5, 0., -5.), Vector(0.1, 1., 1.)),
+            &[6.80798, 7.08872],
+        );
+    }
+
+    #[test]
+    pub fn nearly_axis_parallel_ray_hits_both_walls() {
+        let xs = intersect(&CYLINDER, Point::new(0.5, 0., 0.), Vector(1e-7, 1., 0.));
+
+        assert_eq!(xs.len(), 2, "intersections {xs:?}");
+        assert!((xs[0] / -1.5e7 - 1.).abs() < 1e-6);
+        assert!((xs[1] / 5e6 - 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    pub fn normal_on_side() {
+        assert_eq!(
+            CYLINDER.normal_at(&Point::new(1., 0., 0.), &Intersection::new(0., &CYLINDER)),
+            Normal::new(1., 0., 0.)
+        );
+        assert_eq!(
+            CYLINDER.normal_at(&Point::new(0., 5., -1.), &Intersection::new(0., &CYLINDER)),
+            Normal::new(0., 0., -1.)
+        );
+        assert_eq!(
+            CYLINDER.normal_at(&Point::new(0., -2., 1.), &Intersection::new(0., &CYLINDER)),
+            Normal::new(0., 0., 1.)
+        );
+        assert_eq!(
+            CYLINDER.normal_at(&Point::new(-1., 1., 0.), &Intersection::new(0., &CYLINDER)),
+            Normal::new(-1., 0., 0.)
+        );
+    }
+
+    #[test]
+    pub fn truncated_cylinder() {
+        let cylinder = Cylinder::new().with_limits(1., 2.);
+        let cases = [
+            (Point::new(0., 1.5, 0.), Vector(0.1, 1., 0.), 0),
+            (Point::new(0., 3., -5.), Vector(0., 0., 1.), 0),
+            (Point::new(0., 0., -5.), Vector(0., 0., 1.), 0),
+            (Point::new(0., 2., -5.), Vector(0., 0., 1.), 0),
+            (Point::new(0., 1., -5.), Vector(0., 0., 1.), 0),
+            (Point::new(0., 1.5, -2.), Vector(0., 0., 1.), 2),
+        ];
+        for (origin, direction, count) in cases {
+            assert_eq!(intersect(&cylinder, origin, direction).len(), count);
+        }
+    }
+
+    #[test]
+    pub fn closed_cylinder_caps() {
+        let cylinder = Cylinder::new().with_limits(1., 2.).with_caps(true);
+        let cases = [
+            (Point::new(0., 3., 0.), Vector(0., -1., 0.), 2),
+            (Point::new(0., 3., -2.), Vector(0., -1., 2.), 2),
+            (Point::new(0., 4., -2.), Vector(0., -1., 1.), 2),
+            (Point::new(0., 0., -2.), Vector(0., 1., 2.), 2),
+            (Point::new(0., -1., -2.), Vector(0., 1., 1.), 2),
+        ];
+        for (origin, direction, count) in cases {
+            assert_eq!(intersect(&cylinder, origin, direction).len(), count);
+        }
+    }
+
+    #[test]
+    pub fn normal_on_caps() {
+        let cylinder = Cylinder::new().with_limits(1., 2.).with_caps(true);
+        let cases = [
+            (Point::new(0., 1., 0.), Normal::new(0., -1., 0.)),
+            (Point::new(0.5, 1., 0.), Normal::new(0., -1., 0.)),
+            (Point::new(0., 1., 0.5), Normal::new(0., -1., 0.)),
+            (Point::new(0., 2., 0.), Normal::new(0., 1., 0.)),
+            (Point::new(0.5, 2., 0.), Normal::new(0., 1., 0.)),
+            (Point::new(0., 2., 0.5), Normal::new(0., 1., 0.)),
+        ];
+        for (pt, normal) in cases {
+            assert_eq!(
+                cylinder.normal_at(&pt, &Intersection::new(0., &cylinder)),
+                normal
+            );
+        }
+    }
+
+    #[test]
+    pub fn bounds_follow_truncation() {
+        assert!(!CYLINDER.bounds().is_finite());
+        assert_eq!(
+            Cylinder::new().with_limits(-1., 3.).bounds(),
+            Aabb::new(Point::new(-1., -1., -1.), Point::new(1., 3., 1.))
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use std::sync::OnceLock;
+
+use crate::geometry::{Aabb, Bvh, Ray};
+
+use super::{material::DEFAULT_MATERIAL, Intersection, Material, Shape, Transform};
+
+pub struct Group {
+    material: Option<Material>,
+    transform: Transform,
+    children: Vec<Box<dyn Shape>>,
+    bounds: Aabb,
+    bvh: OnceLock<Bvh>,
+}
+
+impl Group {
+    pub const fn new() -> Group {
+        Group {
+            material: None,
+            transform: Transform::IDENTITY,
+            children: vec![],
+            bounds: Aabb::EMPTY,
+            bvh: OnceLock::new(),
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = Some(material);
+        self
+    }
+
+    pub fn with_child(mut self, child: Box<dyn Shape>) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    pub fn add_child(&mut self, child: Box<dyn Shape>) {
+        self.bounds = self.bounds.union(&child.bounds());
+        self.children.push(child);
+        self.bvh = OnceLock::new();
+    }
+
+    pub fn children(&self) -> &[Box<dyn Shape>] {
+        &self.children
+    }
+
+    fn bvh(&self) -> &Bvh {
+        self.bvh.get_or_init(|| {
+            let bounds: Vec<_> = self.children.iter().map(|child| child.bounds()).collect();
+            Bvh::build(&bounds)
+        })
+    }
+}
+
+impl Default for Group {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shape for Group {
+    fn material(&self) -> &Material {
+        self.material.as_ref().unwrap_or(&DEFAULT_MATERIAL)
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        if self.bounds.intersect_with(ray).is_none() {
+            return vec![];
+        }
+        let mut xs: Vec<Intersection> = vec![];
+        self.bvh().for_each_candidate(ray, f64::INFINITY, |index| {
+            xs.extend(self.children[index].intersect_with(ray));
+        });
+        for hit in &mut xs {
+            hit.parents.push(&self.transform);
+            if let Some(material) = &self.material {
+                hit.material_override = Some(material);
+            }
+        }
+        xs.sort_by(|a, b| a.t.total_cmp(&b.t));
+        xs
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        self.bounds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        drawing::Color,
+        geometry::{Normal, Point, UnitVector, Vector},
+        shapes::{Plane, Sphere},
+    };
+
+    use super::*;
+
+    #[test]
+    pub fn empty_group_is_never_hit() {
+        let ray = Ray::new(Point::ZERO, UnitVector::Z);
+        assert!(Group::new().intersect_with(&ray).is_empty());
+    }
+
+    #[test]
+    pub fn intersect_children_in_order() {
+        let s1 = Sphere::new();
+        let s2 = Sphere::new().with_transform(Transform::translate(0., 0., -3.));
+        let s3 = Sphere::new().with_transform(Transform::translate(5., 0., 0.));
+        let group = Group::new()
+            .with_child(Box::new(s1))
+            .with_child(Box::new(s2))
+            .with_child(Box::new(s3));
+
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+        let xs = group.intersect_with(&ray);
+        let children = group.children();
+        let hit_shapes: Vec<*const dyn Shape> = xs.iter().map(|x| x.shape as *const _).collect();
+
+        assert_eq!(
+            xs.iter().map(|x| x.t).collect::<Vec<_>>(),
+            vec![1., 3., 4., 6.]
+        );
+        assert!(std::ptr::addr_eq(hit_shapes[0], children[1].as_ref()));
+        assert!(std::ptr::addr_eq(hit_shapes[1], children[1].as_ref()));
+        assert!(std::ptr::addr_eq(hit_shapes[2], children[0].as_ref()));
+        assert!(std::ptr::addr_eq(hit_shapes[3], children[0].as_ref()));
+    }
+
+    #[test]
+    pub fn group_transform_applies_to_children() {
+        let sphere = Sphere::new().with_transform(Transform::translate(5., 0., 0.));
+        let group = Group::new()
+            .with_transform(Transform::scale(2., 2., 2.))
+            .with_child(Box::new(sphere));
+
+        let ray = Ray::new(Point::new(10., 0., -10.), UnitVector::Z);
+        assert_eq!(group.intersect_with(&ray).len(), 2);
+    }
+
+    #[test]
+    pub fn convert_point_from_world_to_object_space() {
+        let sphere = Sphere::new().with_transform(Transform::translate(5., 0., 0.));
+        let inner = Group::new()
+            .with_transform(Transform::scale(2., 2., 2.))
+            .with_child(Box::new(sphere));
+        let outer = Group::new()
+            .with_transform(Transform::rotate_y(PI / 2.))
+            .with_child(Box::new(inner));
+
+        let ray = Ray::new(Point::new(-20., 0., -10.), UnitVector::X);
+        let xs = outer.intersect_with(&ray);
+        let hit = &xs[0];
+
+        assert_eq!(hit.parents.len(), 2);
+        assert_eq!(
+            hit.world_to_object(&Point::new(-2., 0., -10.)),
+            Point::new(0., 0., -1.)
+        );
+    }
+
+    #[test]
+    pub fn convert_normal_from_object_to_world_space() {
+        let sphere = Sphere::new().with_transform(Transform::translate(5., 0., 0.));
+        let inner = Group::new()
+            .with_transform(Transform::scale(1., 2., 3.))
+            .with_child(Box::new(sphere));
+        let outer = Group::new()
+            .with_transform(Transform::rotate_y(PI / 2.))
+            .with_child(Box::new(inner));
+
+        let ray = Ray::new(Point::new(-20., 0., -5.), UnitVector::X);
+        let xs = outer.intersect_with(&ray);
+        let normal = xs[0].normal_at(&Point::new(1.7321, 1.1547, -5.5774));
+
+        assert!(normal
+            .v()
+            .is_approx_equal(&Vector(0.2857, 0.4286, -0.8571), 1e-4));
+    }
+
+    #[test]
+    pub fn group_material_applies_to_children() {
+        let red = Material::default_with_color(Color::new(1., 0., 0.));
+        let inner = Group::new()
+            .with_material(Material::default_with_color(Color::new(0., 1., 0.)))
+            .with_child(Box::new(Sphere::new()));
+        let outer = Group::new().with_material(red).with_child(Box::new(inner));
+        let plain = Group::new().with_child(Box::new(
+            Sphere::new().with_material(Material::default_with_color(Color::new(0., 0., 1.))),
+        ));
+
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+        assert_eq!(
+            outer.intersect_with(&ray)[0].material().color,
+            Color::new(1., 0., 0.)
+        );
+        assert_eq!(
+            plain.intersect_with(&ray)[0].material().color,
+            Color::new(0., 0., 1.)
+        );
+    }
+
+    #[test]
+    pub fn children_added_after_intersecting_are_found() {
+        let mut group = Group::new();
+        for x in 0..20 {
+            let offset = Transform::translate(x as f64 * 3., 0., 0.);
+            group.add_child(Box::new(Sphere::new().with_transform(offset)));
+        }
+        let ray = Ray::new(Point::new(30., 0., -5.), UnitVector::Z);
+        assert_eq!(group.intersect_with(&ray).len(), 2);
+
+        let plane = Transform::rotate_x(PI / 2.).and_then(&Transform::translate(30., 0., 2.));
+        group.add_child(Box::new(Plane::new().with_transform(plane)));
+        let xs = group.intersect_with(&ray);
+        assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<_>>(), vec![4., 6., 7.]);
+    }
+
+    #[test]
+    pub fn bounds_enclose_transformed_children() {
+        let group = Group::new()
+            .with_transform(Transform::translate(0., 1., 0.))
+            .with_child(Box::new(
+                Sphere::new().with_transform(Transform::translate(2., 0., 0.)),
+            ))
+            .with_child(Box::new(Sphere::new()));
+
+        assert_eq!(
+            group.bounds(),
+            Aabb::new(Point::new(-1., 0., -1.), Point::new(3., 2., 1.))
+        );
+    }
+
+    #[test]
+    pub fn nested_groups_move_as_one_unit() {
+        let table = || {
+            let top = Sphere::new().with_transform(Transform::scale(2., 0.1, 2.));
+            Group::new().with_child(Box::new(top))
+        };
+        let moved = Group::new()
+            .with_transform(Transform::translate(10., 0., 0.))
+            .with_child(Box::new(table()));
+
+        let ray = Ray::new(Point::new(10., 5., 0.), UnitVector::Y.flip());
+        let xs = moved.intersect_with(&ray);
+        assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<_>>(), vec![4.9, 5.1]);
+        assert_eq!(
+            xs[0].normal_at(&Point::new(10., 0.1, 0.)),
+            Normal::new(0., 1., 0.)
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use crate::geometry::{Aabb, Ray};
+
+use super::{Intersection, Material, Shape, Transform};
+
+pub struct Instance {
+    geometry: Arc<dyn Shape>,
+    transform: Transform,
+    material: Option<Material>,
+}
+
+impl Instance {
+    pub fn new(geometry: Arc<dyn Shape>) -> Instance {
+        Instance {
+            geometry,
+            transform: Transform::IDENTITY,
+            material: None,
+        }
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = Some(material);
+        self
+    }
+}
+
+impl Shape for Instance {
+    fn material(&self) -> &Material {
+        self.material
+            .as_ref()
+            .unwrap_or_else(|| self.geometry.material())
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        self.geometry
+            .intersect_with(ray)
+            .into_iter()
+            .map(|mut hit| {
+                hit.parents.push(&self.transform);
+                if let Some(material) = &self.material {
+                    hit.material_override = Some(material);
+                }
+                hit
+            })
+            .collect()
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        self.geometry.bounds()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        drawing::Color,
+        geometry::{Normal, Point, UnitVector, Vector},
+        shapes::{Group, Sphere},
+    };
+
+    use super::*;
+
+    fn unit_sphere() -> Arc<dyn Shape> {
+        Arc::new(Sphere::new().with_material(Material::default_with_color(Color::new(0., 1., 0.))))
+    }
+
+    #[test]
+    fn instances_share_geometry() {
+        let geometry = unit_sphere();
+        let _first = Instance::new(geometry.clone());
+        let _second = Instance::new(geometry.clone());
+
+        assert_eq!(Arc::strong_count(&geometry), 3);
+    }
+
+    #[test]
+    fn each_instance_applies_its_own_transform() {
+        let geometry = unit_sphere();
+        let near =
+            Instance::new(geometry.clone()).with_transform(Transform::translate(0., 0., -2.));
+        let far = Instance::new(geometry).with_transform(Transform::scale(2., 2., 2.));
+
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+        let near_xs: Vec<f64> = near.intersect_with(&ray).iter().map(|x| x.t).collect();
+        let far_xs: Vec<f64> = far.intersect_with(&ray).iter().map(|x| x.t).collect();
+        assert_eq!(near_xs, vec![2., 4.]);
+        assert_eq!(far_xs, vec![3., 7.]);
+    }
+
+    #[test]
+    fn normals_and_points_pass_through_instance_transform() {
+        let geometry: Arc<dyn Shape> =
+            Arc::new(Sphere::new().with_transform(Transform::translate(5., 0., 0.)));
+        let instance = Instance::new(geometry)
+            .with_transform(Transform::scale(1., 2., 3.).and_then(&Transform::rotate_y(PI / 2.)));
+
+        let ray = Ray::new(Point::new(-20., 0., -5.), UnitVector::X);
+        let xs = instance.intersect_with(&ray);
+        let normal = xs[0].normal_at(&Point::new(1.7321, 1.1547, -5.5774));
+        assert!(normal
+            .v()
+            .is_approx_equal(&Vector(0.2857, 0.4286, -0.8571), 1e-4));
+        assert_eq!(
+            xs[0].world_to_object(&Point::new(0., 0., -6.)),
+            Point::new(1., 0., 0.)
+        );
+    }
+
+    #[test]
+    fn material_override_applies_to_hits() {
+        let geometry = unit_sphere();
+        let plain = Instance::new(geometry.clone());
+        let red = Instance::new(geometry)
+            .with_material(Material::default_with_color(Color::new(1., 0., 0.)));
+
+        let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
+        let plain_hit = &plain.intersect_with(&ray)[0];
+        let red_hit = &red.intersect_with(&ray)[0];
+        assert_eq!(plain_hit.material().color, Color::new(0., 1., 0.));
+        assert_eq!(red_hit.material().color, Color::new(1., 0., 0.));
+        assert!(!plain_hit.is_same_surface(red_hit));
+    }
+
+    #[test]
+    fn grouped_geometry_can_be_instanced() {
+        let tree: Arc<dyn Shape> = Arc::new(
+            Group::new()
+                .with_child(Box::new(Sphere::new()))
+                .with_child(Box::new(
+                    Sphere::new().with_transform(Transform::translate(0., 3., 0.)),
+                )),
+        );
+        let instance = Instance::new(tree).with_transform(Transform::translate(10., 0., 0.));
+
+        assert_eq!(
+            instance.bounds(),
+            Aabb::new(Point::new(9., -1., -1.), Point::new(11., 4., 1.))
+        );
+        let ray = Ray::new(Point::new(10., 10., 0.), UnitVector::Y.flip());
+        let xs = instance.intersect_with(&ray);
+        assert_eq!(
+            xs.iter().map(|x| x.t).collect::<Vec<_>>(),
+            vec![6., 8., 9., 11.]
+        );
+        assert_eq!(
+            xs[0].normal_at(&Point::new(10., 4., 0.)),
+            Normal::new(0., 1., 0.)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use crate::geometry::{Normal, Point};
+
+use super::{Material, Surface, Transform};
+
+#[derive(Clone)]
+pub struct Intersection<'a> {
+    pub t: f64,
+    pub u: f64,
+    pub v: f64,
+    pub face: usize,
+    pub shape: &'a dyn Surface,
+    /// Transforms of the enclosing composites, innermost first.
+    pub parents: Vec<&'a Transform>,
+    pub material_override: Option<&'a Material>,
+}
+
+impl<'a> Intersection<'a> {
+    pub fn new(t: f64, shape: &'a dyn Surface) -> Intersection<'a> {
+        Self::with_uv(t, 0., 0., shape)
+    }
+
+    pub fn with_uv(t: f64, u: f64, v: f64, shape: &'a dyn Surface) -> Intersection<'a> {
+        Intersection {
+            t,
+            u,
+            v,
+            face: 0,
+            shape,
+            parents: vec![],
+            material_override: None,
+        }
+    }
+
+    pub fn with_face(mut self, face: usize) -> Self {
+        self.face = face;
+        self
+    }
+
+    pub fn material(&self) -> &'a Material {
+        self.material_override
+            .unwrap_or_else(|| self.shape.material())
+    }
+
+    pub fn is_same_surface(&self, other: &Intersection) -> bool {
+        std::ptr::addr_eq(self.shape, other.shape)
+            && self.parents.len() == other.parents.len()
+            && self
+                .parents
+                .iter()
+                .zip(&other.parents)
+                .all(|(a, b)| std::ptr::eq(*a, *b))
+    }
+
+    pub fn world_to_object(&self, pt: &Point) -> Point {
+        self.shape.world_to_object(&self.group_point(pt))
+    }
+
+    pub fn normal_at(&self, pt: &Point) -> Normal {
+        let group_normal = self.shape.normal_at(&self.group_point(pt), self);
+        self.parents.iter().fold(group_normal, |normal, parent| {
+            parent.apply_to_normal(&normal.v())
+        })
+    }
+
+    fn group_point(&self, pt: &Point) -> Point {
+        self.parents
+            .iter()
+            .rev()
+            .fold(*pt, |pt, parent| parent.apply_inverse(&pt))
+    }
+}
@@ -1,15 +1,22 @@
 use crate::{
     drawing::Color,
     geometry::{Normal, Point, UnitVector},
+    patterns::Pattern,
     raycaster::PointLight,
 };
 
+pub(super) static DEFAULT_MATERIAL: Material = Material::default();
+
 pub struct Material {
-    color: Color,
-    ambient: f64,
+    pub color: Color,
+    pub ambient: f64,
     pub diffuse: f64,
     pub specular: f64,
-    shininess: i32,
+    pub shininess: i32,
+    pub reflective: f64,
+    pub transparency: f64,
+    pub refractive_index: f64,
+    pub pattern: Option<Box<dyn Pattern>>,
 }
 
 impl Material {
@@ -24,6 +31,10 @@ impl Material {
             diffuse: 0.9,
             specular: 0.9,
             shininess: 200,
+            reflective: 0.,
+            transparency: 0.,
+            refractive_index: 1.,
+            pattern: None,
         }
     }
 
@@ -31,6 +42,7 @@ impl Material {
         &self,
         light: &PointLight,
         position: &Point,
+        object_point: &Point,
         eye_direction: &UnitVector,
         normal: &Normal,
         is_shadowed: bool,
@@ -41,12 +53,20 @@ impl Material {
         let specular_factor =
             shadow_factor * self.specular(&light_direction, eye_direction, normal);
 
-        let effective_color = &light.intensity * &self.color * (self.ambient + diffuse_factor);
+        let effective_color =
+            &light.intensity * self.color_at(object_point) * (self.ambient + diffuse_factor);
         &effective_color + &light.intensity * specular_factor
     }
 
+    pub fn color_at(&self, object_point: &Point) -> Color {
+        match &self.pattern {
+            Some(pattern) => pattern.color_at(object_point),
+            None => self.color.clone(),
+        }
+    }
+
     fn diffuse(&self, light_direction: &UnitVector, normal: &Normal) -> f64 {
-        let light_dot_normal = normal.dot(&light_direction);
+        let light_dot_normal = normal.dot(light_direction);
         if light_dot_normal < 0. {
             return 0.;
         }
@@ -59,7 +79,7 @@ impl Material {
         eye_direction: &UnitVector,
         normal: &Normal,
     ) -> f64 {
-        let reflection = normal.reflect(&light_direction);
+        let reflection = normal.reflect(light_direction);
         let reflect_dot_eye = eye_direction.dot(&reflection);
         if (reflect_dot_eye) <= 0. {
             return 0.;
@@ -71,6 +91,7 @@ impl Material {
 
 #[cfg(test)]
 mod tests {
+    #[allow(clippy::toplevel_ref_arg)]
     mod default_material_lighting {
         use crate::{
             drawing::Color,
@@ -94,7 +115,7 @@ mod tests {
                 let position = &POSITION;
                 let eye_direction = &eye_d;
                 let normal = &normal;
-                this.lighting(light, position, eye_direction, normal, false)
+                this.lighting(light, position, position, eye_direction, normal, false)
             };
             assert_eq!(result, Color::new(1.9, 1.9, 1.9))
         }
@@ -111,7 +132,7 @@ mod tests {
                 let position = &POSITION;
                 let eye_direction = &eye_d;
                 let normal = &normal;
-                this.lighting(light, position, eye_direction, normal, false)
+                this.lighting(light, position, position, eye_direction, normal, false)
             };
             assert_eq!(result, Color::new(1.0, 1.0, 1.0))
         }
@@ -128,7 +149,7 @@ mod tests {
                 let position = &POSITION;
                 let eye_direction = &eye_d;
                 let normal = &normal;
-                this.lighting(light, position, eye_direction, normal, false)
+                this.lighting(light, position, position, eye_direction, normal, false)
             };
             assert_eq!(result, Color::new(0.7364, 0.7364, 0.7364))
         }
@@ -145,7 +166,7 @@ mod tests {
                 let position = &POSITION;
                 let eye_direction = &eye_d;
                 let normal = &normal;
-                this.lighting(light, position, eye_direction, normal, false)
+                this.lighting(light, position, position, eye_direction, normal, false)
             };
             assert_eq!(result, Color::new(1.6364, 1.6364, 1.6364))
         }
@@ -162,7 +183,7 @@ mod tests {
                 let position = &POSITION;
                 let eye_direction = &eye_d;
                 let normal = &normal;
-                this.lighting(light, position, eye_direction, normal, false)
+                this.lighting(light, position, position, eye_direction, normal, false)
             };
             assert_eq!(result, Color::new(0.1, 0.1, 0.1))
         }
@@ -179,7 +200,7 @@ mod tests {
                 let position = &POSITION;
                 let eye_direction = &eye_d;
                 let normal = &normal;
-                this.lighting(light, position, eye_direction, normal, false)
+                this.lighting(light, position, position, eye_direction, normal, false)
             };
             assert_eq!(result, Color::new(1., 0., 0.))
         }
@@ -197,7 +218,7 @@ mod tests {
                 let position = &POSITION;
                 let eye_direction = &eye_d;
                 let normal = &normal;
-                this.lighting(light, position, eye_direction, normal, false)
+                this.lighting(light, position, position, eye_direction, normal, false)
             };
             assert_eq!(result, Color::new(1., 0., 0.))
         }
@@ -208,8 +229,58 @@ mod tests {
             let eye_d = Vector(0., 0., -1.).normalize();
             let normal = Normal::new(0., 0., -1.);
 
-            let result = MATERIAL.lighting(&light, &POSITION, &eye_d, &normal, true);
+            let result = MATERIAL.lighting(&light, &POSITION, &POSITION, &eye_d, &normal, true);
             assert_eq!(result, Color::new(0.1, 0.1, 0.1))
         }
     }
+
+    mod patterned_material_lighting {
+        use crate::{
+            drawing::Color,
+            geometry::{Normal, Point, Vector},
+            patterns::Stripes,
+            raycaster::PointLight,
+            shapes::Material,
+        };
+
+        fn striped_material() -> Material {
+            let mut material = Material::default();
+            material.pattern = Some(Box::new(Stripes::new(Color::WHITE, Color::BLACK)));
+            material.ambient = 1.;
+            material.diffuse = 0.;
+            material.specular = 0.;
+            material
+        }
+
+        #[test]
+        fn surface_color_comes_from_pattern() {
+            let material = striped_material();
+            let light = PointLight::new(Color::WHITE, Point::new(0., 0., -10.));
+            let eye_d = Vector(0., 0., -1.).normalize();
+            let normal = Normal::new(0., 0., -1.);
+
+            let position = Point::new(0.9, 0., 0.);
+            let c1 = material.lighting(&light, &position, &position, &eye_d, &normal, false);
+            let position = Point::new(1.1, 0., 0.);
+            let c2 = material.lighting(&light, &position, &position, &eye_d, &normal, false);
+
+            assert_eq!(c1, Color::WHITE);
+            assert_eq!(c2, Color::BLACK);
+        }
+
+        #[test]
+        fn pattern_is_evaluated_at_object_point() {
+            let material = striped_material();
+            let light = PointLight::new(Color::WHITE, Point::new(0., 0., -10.));
+            let eye_d = Vector(0., 0., -1.).normalize();
+            let normal = Normal::new(0., 0., -1.);
+
+            let position = Point::new(1.5, 0., 0.);
+            let object_point = Point::new(0.5, 0., 0.);
+            let result =
+                material.lighting(&light, &position, &object_point, &eye_d, &normal, false);
+
+            assert_eq!(result, Color::WHITE);
+        }
+    }
 }
@@ -0,0 +1,266 @@
+use crate::geometry::{Aabb, Bvh, Point, Ray, Vector};
+
+use super::{
+    triangle::intersect_triangle, Intersection, Material, Shape, Surface, Transform, Triangle,
+};
+
+pub struct Mesh {
+    material: Material,
+    transform: Transform,
+    vertices: Vec<Point>,
+    normals: Vec<Vector>,
+    faces: Vec<[usize; 3]>,
+    skipped_faces: usize,
+    bvh: Bvh,
+    bounds: Aabb,
+}
+
+impl Mesh {
+    /// Builds a mesh from indexed faces, dropping any that are degenerate.
+    /// Panics if a face refers to a vertex index outside `vertices`; the
+    /// importers validate indices before building a mesh.
+    pub fn new(vertices: Vec<Point>, faces: Vec<[usize; 3]>) -> Mesh {
+        if let Some(index) = faces
+            .iter()
+            .flatten()
+            .find(|&&index| index >= vertices.len())
+        {
+            panic!("mesh face refers to missing vertex {index}");
+        }
+        let face_count = faces.len();
+        let faces: Vec<[usize; 3]> = faces
+            .into_iter()
+            .filter(|face| !Triangle::is_degenerate(face.map(|index| vertices[index])))
+            .collect();
+        let face_bounds: Vec<Aabb> = faces
+            .iter()
+            .map(|face| Aabb::from_points(&face.map(|index| vertices[index])))
+            .collect();
+        Mesh {
+            material: Material::default(),
+            transform: Transform::IDENTITY,
+            bounds: Aabb::from_points(&vertices),
+            bvh: Bvh::build(&face_bounds),
+            vertices,
+            normals: vec![],
+            skipped_faces: face_count - faces.len(),
+            faces,
+        }
+    }
+
+    /// Panics unless there is exactly one normal per vertex.
+    pub fn with_normals(mut self, normals: Vec<Vector>) -> Self {
+        assert_eq!(
+            normals.len(),
+            self.vertices.len(),
+            "mesh needs one normal per vertex"
+        );
+        self.normals = normals;
+        self
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn face_count(&self) -> usize {
+        self.faces.len()
+    }
+
+    pub fn skipped_faces(&self) -> usize {
+        self.skipped_faces
+    }
+
+    fn face_edges(&self, face: usize) -> (Point, Vector, Vector) {
+        let [p1, p2, p3] = self.faces[face].map(|index| self.vertices[index]);
+        (p1, p2 - p1, p3 - p1)
+    }
+}
+
+impl Shape for Mesh {
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let mut xs = vec![];
+        self.bvh.for_each_candidate(ray, f64::INFINITY, |face| {
+            let (p1, e1, e2) = self.face_edges(face);
+            if let Some((t, u, v)) = intersect_triangle(ray, &p1, &e1, &e2) {
+                xs.push(Intersection::with_uv(t, u, v, self).with_face(face));
+            }
+        });
+        xs.sort_by(|a, b| a.t.total_cmp(&b.t));
+        xs
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        self.bounds
+    }
+}
+
+impl Surface for Mesh {
+    fn local_normal_at(&self, _pt: &Point, hit: &Intersection) -> Vector {
+        if self.normals.is_empty() {
+            let (_, e1, e2) = self.face_edges(hit.face);
+            return e2.cross(&e1);
+        }
+        let [n1, n2, n3] = self.faces[hit.face].map(|index| self.normals[index]);
+        n2 * hit.u + n3 * hit.v + n1 * (1. - hit.u - hit.v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Normal, UnitVector};
+    use crate::shapes::Triangle;
+
+    use super::*;
+
+    fn square() -> Mesh {
+        Mesh::new(
+            vec![
+                Point::new(-1., -1., 0.),
+                Point::new(1., -1., 0.),
+                Point::new(1., 1., 0.),
+                Point::new(-1., 1., 0.),
+            ],
+            vec![[0, 2, 1], [0, 3, 2]],
+        )
+    }
+
+    fn grid(size: usize) -> Mesh {
+        let mut vertices = vec![];
+        for y in 0..=size {
+            for x in 0..=size {
+                vertices.push(Point::new(x as f64, y as f64, (x * y % 3) as f64 * 0.1));
+            }
+        }
+        let row = size + 1;
+        let mut faces = vec![];
+        for y in 0..size {
+            for x in 0..size {
+                let corner = y * row + x;
+                faces.push([corner, corner + row, corner + 1]);
+                faces.push([corner + 1, corner + row, corner + row + 1]);
+            }
+        }
+        Mesh::new(vertices, faces)
+    }
+
+    #[test]
+    fn hits_report_the_face_index() {
+        let mesh = square();
+
+        let ray = Ray::new(Point::new(0.5, -0.5, -2.), UnitVector::Z);
+        let xs = mesh.intersect_with(&ray);
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 2.);
+        assert_eq!(xs[0].face, 0);
+
+        let ray = Ray::new(Point::new(-0.5, 0.5, -2.), UnitVector::Z);
+        assert_eq!(mesh.intersect_with(&ray)[0].face, 1);
+    }
+
+    #[test]
+    fn flat_normal_matches_triangle_winding() {
+        let mesh = square();
+        let ray = Ray::new(Point::new(0.5, -0.5, -2.), UnitVector::Z);
+        let hit = &mesh.intersect_with(&ray)[0];
+
+        let [p1, p2, p3] = mesh.faces[0].map(|index| mesh.vertices[index]);
+        let triangle = Triangle::new(p1, p2, p3);
+        assert_eq!(
+            hit.normal_at(&Point::new(0.5, -0.5, 0.)),
+            triangle.normal_at(&Point::ZERO, &Intersection::new(0., &triangle))
+        );
+    }
+
+    #[test]
+    fn normals_are_interpolated_within_the_hit_face() {
+        let mesh = square().with_normals(vec![
+            Vector(-1., 0., -1.),
+            Vector(0., 0., -1.),
+            Vector(1., 0., -1.),
+            Vector(0., 0., -1.),
+        ]);
+
+        let at_vertex = Intersection::with_uv(0., 1., 0., &mesh).with_face(0);
+        assert_eq!(
+            mesh.normal_at(&Point::ZERO, &at_vertex),
+            Normal::new(1., 0., -1.)
+        );
+        let at_vertex = Intersection::with_uv(0., 0., 0., &mesh).with_face(1);
+        assert_eq!(
+            mesh.normal_at(&Point::ZERO, &at_vertex),
+            Normal::new(-1., 0., -1.)
+        );
+    }
+
+    #[test]
+    fn mesh_matches_individual_triangles() {
+        let mesh = grid(8).with_transform(Transform::rotate_x(0.3));
+        let triangles: Vec<Triangle> = mesh
+            .faces
+            .iter()
+            .map(|face| {
+                let [p1, p2, p3] = face.map(|index| mesh.vertices[index]);
+                Triangle::new(p1, p2, p3).with_transform(Transform::rotate_x(0.3))
+            })
+            .collect();
+
+        for i in 0..50 {
+            let origin = Point::new(i as f64 * 0.17, (i * 7 % 50) as f64 * 0.16, -5.);
+            let ray = Ray::new(origin, Vector(0.05, -0.02, 1.).normalize());
+            let expected: Vec<f64> = triangles
+                .iter()
+                .flat_map(|triangle| triangle.intersect_with(&ray))
+                .map(|x| x.t)
+                .collect();
+            let actual: Vec<f64> = mesh.intersect_with(&ray).iter().map(|x| x.t).collect();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn degenerate_faces_are_skipped() {
+        let mut vertices = square().vertices;
+        vertices.push(Point::new(2., 2., 0.));
+        let mesh = Mesh::new(vertices, vec![[0, 2, 1], [0, 2, 4], [1, 1, 3]]);
+
+        assert_eq!(mesh.face_count(), 1);
+        assert_eq!(mesh.skipped_faces(), 2);
+        let ray = Ray::new(Point::new(0.5, -0.5, -2.), UnitVector::Z);
+        assert_eq!(mesh.intersect_with(&ray)[0].face, 0);
+    }
+
+    #[test]
+    fn bounds_enclose_all_vertices() {
+        assert_eq!(
+            square().bounds(),
+            Aabb::new(Point::new(-1., -1., 0.), Point::new(1., 1., 0.))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "mesh face refers to missing vertex 1")]
+    fn faces_must_refer_to_existing_vertices() {
+        Mesh::new(vec![Point::ZERO], vec![[0, 0, 1]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "mesh needs one normal per vertex")]
+    fn normals_must_match_the_vertices() {
+        square().with_normals(vec![Vector(0., 0., -1.)]);
+    }
+}
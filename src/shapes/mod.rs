@@ -1,11 +1,33 @@
+mod cone;
+mod csg;
+mod cube;
+mod cylinder;
+mod group;
+mod instance;
+mod intersection;
 mod material;
+mod mesh;
 mod plane;
 mod shape;
+mod smooth_triangle;
 mod sphere;
+mod torus;
 mod transform;
+mod triangle;
 
+pub use cone::Cone;
+pub use csg::{Csg, CsgOperation};
+pub use cube::Cube;
+pub use cylinder::Cylinder;
+pub use group::Group;
+pub use instance::Instance;
+pub use intersection::Intersection;
 pub use material::Material;
+pub use mesh::Mesh;
 pub use plane::Plane;
-pub use shape::Shape;
+pub use shape::{Shape, Surface};
+pub use smooth_triangle::SmoothTriangle;
 pub use sphere::Sphere;
+pub use torus::Torus;
 pub use transform::Transform;
+pub use triangle::Triangle;
@@ -1,6 +1,6 @@
-use crate::geometry::{Normal, Point, Ray, UnitVector};
+use crate::geometry::{Aabb, Point, Ray, UnitVector, Vector};
 
-use super::{Material, Shape, Transform};
+use super::{Intersection, Material, Shape, Surface, Transform};
 
 pub struct Plane {
     material: Material,
@@ -28,44 +28,73 @@ impl Plane {
     }
 }
 
+impl Default for Plane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Shape for Plane {
     fn material(&self) -> &Material {
         &self.material
     }
 
-    fn normal_at(&self, _pt: &Point) -> Normal {
-        let vector = self.transform.apply(&UnitVector::Y);
-        Normal::from(&vector)
+    fn transform(&self) -> &Transform {
+        &self.transform
     }
 
-    fn intersect_with(&self, ray: &Ray) -> Vec<f64> {
-        let local_ray = self.transform.inverse().apply(ray);
-        let direction_y = local_ray.direction.1;
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let direction_y = ray.direction.1;
         if direction_y.abs() < Plane::EPSILON {
             vec![]
         } else {
-            vec![-local_ray.origin.y() / direction_y]
+            vec![Intersection::new(-ray.origin.y() / direction_y, self)]
         }
     }
+
+    fn local_bounds(&self) -> Aabb {
+        Aabb::new(
+            Point::new(f64::NEG_INFINITY, 0., f64::NEG_INFINITY),
+            Point::new(f64::INFINITY, 0., f64::INFINITY),
+        )
+    }
+}
+
+impl Surface for Plane {
+    fn local_normal_at(&self, _pt: &Point, _hit: &Intersection) -> Vector {
+        UnitVector::Y.v()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::f64::consts::{PI, SQRT_2};
 
-    use crate::{geometry::Ray, shapes::Transform};
+    use crate::{
+        geometry::{Normal, Ray},
+        shapes::Transform,
+    };
 
     use super::*;
 
-    const PLANE: Plane = Plane::new();
+    static PLANE: Plane = Plane::new();
 
     #[test]
     pub fn normal_is_constant() {
         let y_axis_normal = Normal::new(0., 1., 0.);
 
-        assert_eq!(PLANE.normal_at(&Point::ZERO), y_axis_normal);
-        assert_eq!(PLANE.normal_at(&Point::new(10., 0., -10.)), y_axis_normal);
-        assert_eq!(PLANE.normal_at(&Point::new(-5., 0., 150.)), y_axis_normal);
+        assert_eq!(
+            PLANE.normal_at(&Point::ZERO, &Intersection::new(0., &PLANE)),
+            y_axis_normal
+        );
+        assert_eq!(
+            PLANE.normal_at(&Point::new(10., 0., -10.), &Intersection::new(0., &PLANE)),
+            y_axis_normal
+        );
+        assert_eq!(
+            PLANE.normal_at(&Point::new(-5., 0., 150.), &Intersection::new(0., &PLANE)),
+            y_axis_normal
+        );
     }
 
     #[test]
@@ -89,7 +118,8 @@ mod tests {
         let ray = Ray::new(Point::new(0., 1., 0.), UnitVector::Y.flip());
 
         let intersections = PLANE.intersect_with(&ray);
-        assert_eq!(intersections, vec![1.])
+        assert_eq!(intersections.len(), 1);
+        assert_eq!(intersections[0].t, 1.)
     }
 
     #[test]
@@ -97,7 +127,8 @@ mod tests {
         let ray = Ray::new(Point::new(0., -1., 0.), UnitVector::Y);
 
         let intersections = PLANE.intersect_with(&ray);
-        assert_eq!(intersections, vec![1.])
+        assert_eq!(intersections.len(), 1);
+        assert_eq!(intersections[0].t, 1.)
     }
 
     #[test]
@@ -105,10 +136,44 @@ mod tests {
         let transform = Transform::rotate_x(PI / 4.);
         let plane = Plane::new().with_transform(transform);
 
-        let normal = plane.normal_at(&Point::ZERO);
+        let normal = plane.normal_at(&Point::ZERO, &Intersection::new(0., &plane));
         assert_eq!(normal, Normal::new(0., SQRT_2 / 2., SQRT_2 / 2.))
     }
 
+    #[test]
+    pub fn translation_does_not_affect_normal() {
+        let plane = Plane::new().with_transform(Transform::translate(1., 2., 3.));
+
+        let normal = plane.normal_at(&Point::new(1., 2., 3.), &Intersection::new(0., &plane));
+        assert_eq!(normal, Normal::new(0., 1., 0.))
+    }
+
+    #[test]
+    pub fn apply_non_uniform_scaling_to_normal() {
+        let transform = Transform::rotate_x(PI / 4.).and_then(&Transform::scale(1., 1., 2.));
+        let plane = Plane::new().with_transform(transform);
+
+        let normal = plane.normal_at(&Point::ZERO, &Intersection::new(0., &plane));
+        assert_eq!(normal, Normal::new(0., 2., 1.))
+    }
+
+    #[test]
+    pub fn plane_is_unbounded_along_x_and_z() {
+        let plane = Plane::new().with_transform(Transform::translate(0., 2., 0.));
+
+        let bounds = plane.bounds();
+        assert!(!bounds.is_finite());
+        assert_eq!(bounds.min.y(), 2.);
+        assert_eq!(bounds.max.y(), 2.);
+    }
+
+    #[test]
+    pub fn tilted_plane_is_unbounded_in_every_direction() {
+        let plane = Plane::new().with_transform(Transform::rotate_x(PI / 4.));
+
+        assert_eq!(plane.bounds(), Aabb::INFINITE);
+    }
+
     #[test]
     pub fn apply_transform_to_ray() {
         let transform = Transform::translate(0., -1., 0.);
@@ -116,6 +181,7 @@ mod tests {
 
         let ray = Ray::new(Point::new(0., 1., 0.), UnitVector::Y.flip());
         let intersections = plane.intersect_with(&ray);
-        assert_eq!(intersections, vec![2.])
+        assert_eq!(intersections.len(), 1);
+        assert_eq!(intersections[0].t, 2.)
     }
 }
@@ -1,9 +1,35 @@
-use crate::geometry::{Normal, Point, Ray};
+use crate::geometry::{Aabb, Normal, Point, Ray, Vector};
 
-use super::Material;
+use super::{Intersection, Material, Transform};
 
-pub trait Shape {
+pub trait Shape: Send + Sync {
     fn material(&self) -> &Material;
-    fn normal_at(&self, pt: &Point) -> Normal;
-    fn intersect_with(&self, ray: &Ray) -> Vec<f64>;
+    fn transform(&self) -> &Transform;
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>>;
+    fn local_bounds(&self) -> Aabb;
+
+    fn bounds(&self) -> Aabb {
+        self.transform().apply(&self.local_bounds())
+    }
+
+    fn world_to_object(&self, pt: &Point) -> Point {
+        self.transform().apply_inverse(pt)
+    }
+
+    fn intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let local_ray = self.transform().apply_inverse(ray);
+        self.local_intersect_with(&local_ray)
+    }
+}
+
+// Only leaf shapes have a surface; groups, CSG nodes and instances report
+// their children's hits, whose normals come from `Intersection::normal_at`.
+pub trait Surface: Shape {
+    fn local_normal_at(&self, pt: &Point, hit: &Intersection) -> Vector;
+
+    fn normal_at(&self, pt: &Point, hit: &Intersection) -> Normal {
+        let local_point = self.world_to_object(pt);
+        let local_normal = self.local_normal_at(&local_point, hit);
+        self.transform().apply_to_normal(&local_normal)
+    }
 }
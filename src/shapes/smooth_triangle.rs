@@ -0,0 +1,121 @@
+use crate::geometry::{Aabb, Point, Ray, Vector};
+
+use super::{Intersection, Material, Shape, Surface, Transform, Triangle};
+
+pub struct SmoothTriangle {
+    triangle: Triangle,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+}
+
+impl SmoothTriangle {
+    pub fn new(
+        p1: Point,
+        p2: Point,
+        p3: Point,
+        n1: Vector,
+        n2: Vector,
+        n3: Vector,
+    ) -> SmoothTriangle {
+        SmoothTriangle {
+            triangle: Triangle::new(p1, p2, p3),
+            n1,
+            n2,
+            n3,
+        }
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.triangle = self.triangle.with_material(material);
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.triangle = self.triangle.with_transform(transform);
+        self
+    }
+}
+
+impl Shape for SmoothTriangle {
+    fn material(&self) -> &Material {
+        self.triangle.material()
+    }
+
+    fn transform(&self) -> &Transform {
+        self.triangle.transform()
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        self.triangle
+            .local_intersect_with(ray)
+            .into_iter()
+            .map(|hit| Intersection::with_uv(hit.t, hit.u, hit.v, self))
+            .collect()
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        self.triangle.local_bounds()
+    }
+}
+
+impl Surface for SmoothTriangle {
+    fn local_normal_at(&self, _pt: &Point, hit: &Intersection) -> Vector {
+        self.n2 * hit.u + self.n3 * hit.v + self.n1 * (1. - hit.u - hit.v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Normal, UnitVector};
+
+    use super::*;
+
+    fn smooth_triangle() -> SmoothTriangle {
+        SmoothTriangle::new(
+            Point::new(0., 1., 0.),
+            Point::new(-1., 0., 0.),
+            Point::new(1., 0., 0.),
+            Vector(0., 1., 0.),
+            Vector(-1., 0., 0.),
+            Vector(1., 0., 0.),
+        )
+    }
+
+    #[test]
+    pub fn intersection_stores_uv() {
+        let ray = Ray::new(Point::new(-0.2, 0.3, -2.), UnitVector::Z);
+
+        let t = smooth_triangle();
+        let xs = t.intersect_with(&ray);
+        assert!((xs[0].u - 0.45).abs() < 1e-9);
+        assert!((xs[0].v - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn normal_is_interpolated_from_vertices() {
+        let t = smooth_triangle();
+        let hit = Intersection::with_uv(1., 0.45, 0.25, &t);
+
+        let normal = t.normal_at(&Point::ZERO, &hit);
+        assert_eq!(normal, Normal::new(-0.5547, 0.83205, 0.));
+    }
+
+    #[test]
+    pub fn vertex_normals_are_reproduced_at_vertices() {
+        let t = smooth_triangle();
+
+        assert_eq!(
+            t.normal_at(&Point::ZERO, &Intersection::with_uv(1., 0., 0., &t)),
+            Normal::new(0., 1., 0.)
+        );
+        assert_eq!(
+            t.normal_at(&Point::ZERO, &Intersection::with_uv(1., 1., 0., &t)),
+            Normal::new(-1., 0., 0.)
+        );
+        assert_eq!(
+            t.normal_at(&Point::ZERO, &Intersection::with_uv(1., 0., 1., &t)),
+            Normal::new(1., 0., 0.)
+        );
+    }
+}
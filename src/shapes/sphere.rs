@@ -1,6 +1,6 @@
-use crate::geometry::{Normal, Point, Ray, Vector};
+use crate::geometry::{solve_quadratic_equation, Aabb, Point, Ray, Vector};
 
-use super::{Material, Shape, Transform};
+use super::{Intersection, Material, Shape, Surface, Transform};
 
 pub struct Sphere {
     material: Material,
@@ -12,14 +12,37 @@ impl Shape for Sphere {
         &self.material
     }
 
-    fn normal_at(&self, pt: &Point) -> Normal {
-        let local_point = self.transform.inverse().apply(pt);
-        self.local_normal_at(&local_point)
+    fn transform(&self) -> &Transform {
+        &self.transform
     }
 
-    fn intersect_with(&self, r: &Ray) -> Vec<f64> {
-        let local_ray = self.transform.inverse().apply(r);
-        self.local_intersect_with(&local_ray)
+    fn local_intersect_with(&self, r: &Ray) -> Vec<Intersection<'_>> {
+        let sphere_to_ray: Vector = r.origin.into();
+
+        let a = r.direction.magnitude_squared();
+        let b = 2. * r.direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.magnitude_squared() - Self::RADIUS_SQUARED;
+
+        solve_quadratic_equation(a, b, c)
+            .into_iter()
+            .map(|t| Intersection::new(t, self))
+            .collect()
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        Aabb::new(Point::new(-1., -1., -1.), Point::new(1., 1., 1.))
+    }
+}
+
+impl Surface for Sphere {
+    fn local_normal_at(&self, pt: &Point, _hit: &Intersection) -> Vector {
+        Vector::from(*pt)
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -42,34 +65,6 @@ impl Sphere {
         self.transform = transform;
         self
     }
-
-    fn local_intersect_with(&self, r: &Ray) -> Vec<f64> {
-        let sphere_to_ray: Vector = r.origin.into();
-
-        let a = r.direction.magnitude_squared();
-        let b = 2. * r.direction.dot(&sphere_to_ray);
-        let c = sphere_to_ray.magnitude_squared() - Self::RADIUS_SQUARED;
-
-        solve_quadratic_equation(a, b, c)
-            .map(|(x1, x2)| vec![x1, x2])
-            .unwrap_or(vec![])
-    }
-
-    fn local_normal_at(&self, pt: &Point) -> Normal {
-        Normal::from(pt as &Vector)
-    }
-}
-
-fn solve_quadratic_equation(a: f64, b: f64, c: f64) -> Option<(f64, f64)> {
-    let d = b * b - 4. * a * c;
-    if d < 0. {
-        None
-    } else {
-        let sqrt_d = d.sqrt();
-        let x1 = (-b - sqrt_d) / (2. * a);
-        let x2 = (-b + sqrt_d) / (2. * a);
-        Some((x1, x2))
-    }
 }
 
 #[cfg(test)]
@@ -79,34 +74,37 @@ mod tests {
     static SPHERE: Sphere = Sphere::new();
 
     mod sphere_normals {
-        use super::super::Normal;
         use super::SPHERE;
+        use crate::geometry::Normal;
 
         use crate::geometry::Point;
-        use crate::shapes::Shape;
+        use crate::shapes::{Intersection, Surface};
 
         #[test]
         fn normal_towards_x_axis() {
-            let n = SPHERE.normal_at(&Point::new(1., 0., 0.));
+            let n = SPHERE.normal_at(&Point::new(1., 0., 0.), &Intersection::new(0., &SPHERE));
             assert_eq!(n, Normal::new(1., 0., 0.));
         }
 
         #[test]
         fn normal_towards_y_axis() {
-            let n = SPHERE.normal_at(&Point::new(0., 1., 0.));
+            let n = SPHERE.normal_at(&Point::new(0., 1., 0.), &Intersection::new(0., &SPHERE));
             assert_eq!(n, Normal::new(0., 1., 0.));
         }
 
         #[test]
         fn normal_towards_z_axis() {
-            let n = SPHERE.normal_at(&Point::new(0., 0., 1.));
+            let n = SPHERE.normal_at(&Point::new(0., 0., 1.), &Intersection::new(0., &SPHERE));
             assert_eq!(n, Normal::new(0., 0., 1.));
         }
 
         #[test]
         fn normal_at_non_axial_point() {
-            let sqrt_3 = (3.0 as f64).sqrt();
-            let n = SPHERE.normal_at(&Point::new(sqrt_3 / 3., sqrt_3 / 3., sqrt_3 / 3.));
+            let sqrt_3 = 3.0_f64.sqrt();
+            let n = SPHERE.normal_at(
+                &Point::new(sqrt_3 / 3., sqrt_3 / 3., sqrt_3 / 3.),
+                &Intersection::new(0., &SPHERE),
+            );
             assert_eq!(n, Normal::new(sqrt_3 / 3., sqrt_3 / 3., sqrt_3 / 3.))
         }
     }
@@ -150,14 +148,16 @@ mod tests {
 
         fn intersections_with_ray_from_origin(origin: Point) -> Vec<f64> {
             let ray = Ray::new(origin, UnitVector::Z);
-            SPHERE.intersect_with(&ray)
+            SPHERE.intersect_with(&ray).iter().map(|x| x.t).collect()
         }
     }
 
     mod transformed_sphere {
+        use std::f64::consts::{PI, SQRT_2};
+
         use crate::{
-            geometry::{Normal, Point, Ray, UnitVector},
-            shapes::{Shape, Sphere, Transform},
+            geometry::{Aabb, Normal, Point, Ray, UnitVector},
+            shapes::{Intersection, Shape, Sphere, Surface, Transform},
         };
 
         #[test]
@@ -166,7 +166,7 @@ mod tests {
 
             let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
             let xs = sphere.intersect_with(&ray);
-            assert_eq!(xs, vec![9., 11.]);
+            assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<_>>(), vec![9., 11.]);
         }
 
         #[test]
@@ -175,15 +175,69 @@ mod tests {
 
             let ray = Ray::new(Point::new(0., 0., -5.), UnitVector::Z);
             let xs = sphere.intersect_with(&ray);
-            assert_eq!(xs, vec![3., 7.]);
+            assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<_>>(), vec![3., 7.]);
+        }
+
+        #[test]
+        fn intersect_very_large_sphere_from_its_center() {
+            let sphere = Sphere::new().with_transform(Transform::scale(2e6, 2e6, 2e6));
+
+            let ray = Ray::new(Point::ZERO, UnitVector::Z);
+            let xs = sphere.intersect_with(&ray);
+            assert_eq!(xs.len(), 2);
+            assert!((xs[0].t + 2e6).abs() < 1e-3);
+            assert!((xs[1].t - 2e6).abs() < 1e-3);
+        }
+
+        #[test]
+        fn bounds_of_transformed_sphere() {
+            let transform =
+                Transform::scale(2., 1., 1.).and_then(&Transform::translate(0., 0., 5.));
+            let sphere = Sphere::new().with_transform(transform);
+
+            assert_eq!(
+                sphere.bounds(),
+                Aabb::new(Point::new(-2., -1., 4.), Point::new(2., 1., 6.))
+            );
         }
 
         #[test]
         fn normal_of_translated_sphere() {
             let sphere = Sphere::new().with_transform(Transform::translate(0., 1., 0.));
 
-            let normal = sphere.normal_at(&Point::new(1., 1., 0.));
+            let normal = sphere.normal_at(&Point::new(1., 1., 0.), &Intersection::new(0., &sphere));
             assert_eq!(normal, Normal::new(1., 0., 0.))
         }
+
+        #[test]
+        fn normal_of_rotated_sphere() {
+            let sphere = Sphere::new().with_transform(Transform::rotate_x(PI / 2.));
+
+            let normal = sphere.normal_at(&Point::new(0., 0., 1.), &Intersection::new(0., &sphere));
+            assert_eq!(normal, Normal::new(0., 0., 1.))
+        }
+
+        #[test]
+        fn normal_of_non_uniformly_scaled_sphere() {
+            let sphere = Sphere::new().with_transform(Transform::scale(1., 0.5, 1.));
+
+            let normal = sphere.normal_at(
+                &Point::new(0., SQRT_2 / 4., -SQRT_2 / 2.),
+                &Intersection::new(0., &sphere),
+            );
+            assert_eq!(normal, Normal::new(0., 2., -1.))
+        }
+
+        #[test]
+        fn normal_of_scaled_and_rotated_sphere() {
+            let transform = Transform::scale(1., 0.5, 1.).and_then(&Transform::rotate_y(PI / 2.));
+            let sphere = Sphere::new().with_transform(transform);
+
+            let normal = sphere.normal_at(
+                &Point::new(-SQRT_2 / 2., SQRT_2 / 4., 0.),
+                &Intersection::new(0., &sphere),
+            );
+            assert_eq!(normal, Normal::new(-1., 2., 0.))
+        }
     }
 }
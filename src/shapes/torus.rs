@@ -0,0 +1,191 @@
+use crate::geometry::{solve_quartic_equation, Aabb, Point, Ray, Vector};
+
+use super::{Intersection, Material, Shape, Surface, Transform};
+
+pub struct Torus {
+    material: Material,
+    transform: Transform,
+    major_radius: f64,
+    minor_radius: f64,
+}
+
+impl Torus {
+    pub const fn new() -> Torus {
+        Torus {
+            material: Material::default(),
+            transform: Transform::IDENTITY,
+            major_radius: 1.,
+            minor_radius: 0.25,
+        }
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn with_radii(mut self, major_radius: f64, minor_radius: f64) -> Self {
+        self.major_radius = major_radius;
+        self.minor_radius = minor_radius;
+        self
+    }
+}
+
+impl Default for Torus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shape for Torus {
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let direction = ray.direction;
+        let dd = direction.magnitude_squared();
+        // Solving from the point of closest approach to the center keeps the
+        // quartic coefficients small for rays that start far away
+        let t0 = -Vector::from(ray.origin).dot(&direction) / dd;
+        let origin = Vector::from(ray.position(t0));
+
+        let r2 = self.major_radius * self.major_radius;
+        let od = origin.dot(&direction);
+        let k = origin.magnitude_squared() + r2 - self.minor_radius * self.minor_radius;
+        let a = dd * dd;
+        let b = 4. * dd * od;
+        let c = 2. * dd * k + 4. * od * od
+            - 4. * r2 * (direction.0 * direction.0 + direction.2 * direction.2);
+        let d = 4. * k * od - 8. * r2 * (origin.0 * direction.0 + origin.2 * direction.2);
+        let e = k * k - 4. * r2 * (origin.0 * origin.0 + origin.2 * origin.2);
+
+        solve_quartic_equation(a, b, c, d, e)
+            .into_iter()
+            .map(|t| Intersection::new(t + t0, self))
+            .collect()
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        let extent = self.major_radius + self.minor_radius;
+        Aabb::new(
+            Point::new(-extent, -self.minor_radius, -extent),
+            Point::new(extent, self.minor_radius, extent),
+        )
+    }
+}
+
+impl Surface for Torus {
+    fn local_normal_at(&self, pt: &Point, _hit: &Intersection) -> Vector {
+        let radial = Vector(pt.x(), 0., pt.z());
+        let tube_center = radial * (self.major_radius / radial.magnitude());
+        Vector::from(*pt) - tube_center
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::Normal;
+
+    use super::*;
+
+    static TORUS: Torus = Torus::new();
+
+    fn intersections(torus: &Torus, origin: Point, direction: Vector) -> Vec<f64> {
+        let ray = Ray::new(origin, direction.normalize());
+        torus.intersect_with(&ray).iter().map(|x| x.t).collect()
+    }
+
+    fn assert_distances(actual: Vec<f64>, expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len(), "distances {actual:?}");
+        for (t, expected) in actual.iter().zip(expected) {
+            assert!((t - expected).abs() < 1e-6, "distances {actual:?}");
+        }
+    }
+
+    #[test]
+    fn ray_through_both_sides_of_the_ring() {
+        let xs = intersections(&TORUS, Point::new(-5., 0., 0.), Vector(1., 0., 0.));
+        assert_distances(xs, &[3.75, 4.25, 5.75, 6.25]);
+    }
+
+    #[test]
+    fn ray_through_the_hole_misses() {
+        let xs = intersections(&TORUS, Point::new(0., 5., 0.), Vector(0., -1., 0.));
+        assert!(xs.is_empty());
+
+        let xs = intersections(&TORUS, Point::new(0.5, 5., 0.2), Vector(0., -1., 0.));
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn ray_through_the_tube() {
+        let xs = intersections(&TORUS, Point::new(1., 5., 0.), Vector(0., -1., 0.));
+        assert_distances(xs, &[4.75, 5.25]);
+    }
+
+    #[test]
+    fn oblique_ray_through_the_hole() {
+        let xs = intersections(&TORUS, Point::new(-3., 3., 0.), Vector(1., -1., 0.));
+        assert!(xs.is_empty());
+
+        let xs = intersections(&TORUS, Point::new(0., 3., -3.), Vector(0., -1., 1.));
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn grazing_ray_touches_the_top_of_the_tube() {
+        let xs = intersections(&TORUS, Point::new(-5., 0.25, 0.), Vector(1., 0., 0.));
+        assert_distances(xs, &[4., 4., 6., 6.]);
+
+        let xs = intersections(&TORUS, Point::new(-5., 0.2501, 0.), Vector(1., 0., 0.));
+        assert!(xs.is_empty());
+    }
+
+    #[test]
+    fn grazing_ray_touches_the_inner_rim() {
+        let torus = Torus::new().with_radii(2., 0.5);
+        let xs = intersections(&torus, Point::new(1.5, 0., -5.), Vector(0., 0., 1.));
+        assert_eq!(xs.len(), 4, "{xs:?}");
+        assert!((xs[1] - 5.).abs() < 1e-6 && (xs[2] - 5.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn distant_rays_keep_their_precision() {
+        let xs = intersections(&TORUS, Point::new(-1e4, 0., 0.), Vector(1., 0., 0.));
+        assert_distances(xs, &[1e4 - 1.25, 1e4 - 0.75, 1e4 + 0.75, 1e4 + 1.25]);
+    }
+
+    #[test]
+    fn normals_point_away_from_the_tube_center() {
+        let hit = Intersection::new(0., &TORUS);
+        for (point, normal) in [
+            (Point::new(1.25, 0., 0.), Normal::new(1., 0., 0.)),
+            (Point::new(0.75, 0., 0.), Normal::new(-1., 0., 0.)),
+            (Point::new(0., 0.25, 1.), Normal::new(0., 1., 0.)),
+            (Point::new(0., -0.25, -1.), Normal::new(0., -1., 0.)),
+        ] {
+            assert_eq!(TORUS.normal_at(&point, &hit), normal);
+        }
+    }
+
+    #[test]
+    fn bounds_of_scaled_torus() {
+        let torus = Torus::new()
+            .with_radii(2., 0.5)
+            .with_transform(Transform::scale(1., 2., 1.));
+        assert_eq!(
+            torus.bounds(),
+            Aabb::new(Point::new(-2.5, -1., -2.5), Point::new(2.5, 1., 2.5))
+        );
+    }
+}
@@ -1,70 +1,106 @@
-use crate::geometry::{MatMul, Matrix, Vector};
+use crate::geometry::{MatMul, Matrix, Normal, Point, Quaternion, Vector};
 
 pub struct Transform {
     forward: Matrix,
     inverse: Matrix,
+    inverse_transpose: Matrix,
 }
 
 impl Transform {
     pub const IDENTITY: Transform = Transform {
         forward: Matrix::IDENTITY,
         inverse: Matrix::IDENTITY,
+        inverse_transpose: Matrix::IDENTITY,
     };
 
-    pub fn rotate_x(angle: f64) -> Transform {
+    fn new(forward: Matrix, inverse: Matrix) -> Transform {
         Transform {
-            forward: Matrix::rotate_x(angle),
-            inverse: Matrix::rotate_x(-angle),
+            inverse_transpose: inverse.transpose(),
+            forward,
+            inverse,
         }
     }
 
+    pub fn from_matrix(forward: Matrix) -> Option<Transform> {
+        forward.inverse().map(|inverse| Self::new(forward, inverse))
+    }
+
+    pub fn rotate_x(angle: f64) -> Transform {
+        Self::new(Matrix::rotate_x(angle), Matrix::rotate_x(-angle))
+    }
+
     pub fn rotate_y(angle: f64) -> Transform {
-        Transform {
-            forward: Matrix::rotate_y(angle),
-            inverse: Matrix::rotate_y(-angle),
-        }
+        Self::new(Matrix::rotate_y(angle), Matrix::rotate_y(-angle))
+    }
+
+    pub fn rotate_z(angle: f64) -> Transform {
+        Self::new(Matrix::rotate_z(angle), Matrix::rotate_z(-angle))
+    }
+
+    pub fn rotate_around(axis: &Vector, angle: f64) -> Transform {
+        let axis = axis.normalize();
+        Self::new(
+            Matrix::rotation(&axis, angle),
+            Matrix::rotation(&axis, -angle),
+        )
+    }
+
+    pub fn rotate(rotation: &Quaternion) -> Transform {
+        Self::new(rotation.to_matrix(), rotation.conjugate().to_matrix())
+    }
+
+    pub fn shear(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Option<Transform> {
+        Self::from_matrix(Matrix::shearing(xy, xz, yx, yz, zx, zy))
+    }
+
+    pub fn look_at(from: &Point, to: &Point, up: &Vector) -> Transform {
+        let orientation = Matrix::orientation(from, to, up);
+        Self::new(
+            Matrix::translation(from) * &orientation,
+            orientation.transpose() * Matrix::translation(&from.flip()),
+        )
     }
 
     pub fn translate(tx: f64, ty: f64, tz: f64) -> Transform {
         let v = Vector(tx, ty, tz);
-        Transform {
-            forward: Matrix::translation(&v),
-            inverse: Matrix::translation(&v.flip()),
-        }
+        Self::new(Matrix::translation(&v), Matrix::translation(&v.flip()))
     }
 
     pub fn scale(sx: f64, sy: f64, sz: f64) -> Transform {
         let v = Vector(sx, sy, sz);
-        Transform {
-            forward: Matrix::diag(&v),
-            inverse: Matrix::diag(&v.recip()),
-        }
+        Self::new(Matrix::diag(&v), Matrix::diag(&v.recip()))
     }
 
     pub fn inverse(&self) -> Transform {
-        Transform {
-            forward: self.inverse.clone(),
-            inverse: self.forward.clone(),
-        }
+        Self::new(self.inverse.clone(), self.forward.clone())
     }
 
     pub fn and_then(&self, other: &Transform) -> Transform {
-        Transform {
-            forward: &other.forward * &self.forward,
-            inverse: &self.inverse * &other.inverse,
-        }
+        Self::new(
+            &other.forward * &self.forward,
+            &self.inverse * &other.inverse,
+        )
     }
 
     pub fn apply<R, Obj: MatMul<R>>(&self, object: &Obj) -> R {
         object.matmul(&self.forward)
     }
+
+    pub fn apply_inverse<R, Obj: MatMul<R>>(&self, object: &Obj) -> R {
+        object.matmul(&self.inverse)
+    }
+
+    pub fn apply_to_normal(&self, normal: &Vector) -> Normal {
+        let world_normal = self.inverse_transpose.mul(normal);
+        Normal::from(&world_normal)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::f64::consts::{PI, SQRT_2};
 
-    use crate::geometry::{Point, Vector};
+    use crate::geometry::{Matrix, Normal, Point, Quaternion, Vector};
 
     use super::Transform;
 
@@ -92,6 +128,74 @@ mod tests {
         assert_eq!(restored, original);
     }
 
+    #[test]
+    fn apply_rotation_around_z() {
+        let original = Vector(1., 0., 0.);
+        let transform = Transform::rotate_z(PI / 4.);
+
+        let rotated = transform.apply(&original);
+        let restored = transform.inverse().apply(&rotated);
+
+        assert_eq!(rotated, Vector(SQRT_2 / 2., SQRT_2 / 2., 0.));
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn apply_rotation_around_arbitrary_axis() {
+        let original = Point::new(1., 0., 0.);
+        let transform = Transform::rotate_around(&Vector(0., 0., 2.), PI / 2.);
+
+        let rotated = transform.apply(&original);
+        let restored = transform.inverse().apply(&rotated);
+
+        assert_eq!(rotated, Point::new(0., 1., 0.));
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn apply_quaternion_rotation() {
+        let original = Point::new(0., 0., 1.);
+        let rotation = Quaternion::from_axis_angle(&Vector(0., 1., 0.), PI / 4.);
+        let transform = Transform::rotate(&rotation);
+
+        let rotated = transform.apply(&original);
+        let restored = transform.inverse().apply(&rotated);
+
+        assert_eq!(rotated, Point::new(SQRT_2 / 2., 0., SQRT_2 / 2.));
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn apply_shearing() {
+        let original = Point::new(2., 3., 4.);
+        let transform = Transform::shear(1., 0., 0., 0., 0., 1.).unwrap();
+
+        let sheared = transform.apply(&original);
+        let restored = transform.inverse().apply(&sheared);
+
+        assert_eq!(sheared, Point::new(5., 3., 7.));
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn degenerate_shearing_is_not_a_transform() {
+        assert!(Transform::shear(1., 0., 1., 0., 0., 0.).is_none());
+    }
+
+    #[test]
+    fn apply_look_at() {
+        let from = Point::new(1., 0., 0.);
+        let transform = Transform::look_at(&from, &Point::new(1., 0., -4.), &Vector(0., 1., 0.));
+
+        let original = Point::new(0., 0., 2.);
+        let transformed = transform.apply(&original);
+        let restored = transform.inverse().apply(&transformed);
+
+        assert_eq!(transformed, Point::new(1., 0., -2.));
+        assert_eq!(transform.apply(&Vector(1., 0., 0.)), Vector(-1., 0., 0.));
+        assert_eq!(restored, original);
+    }
+
     #[test]
     fn apply_translation() {
         let original = Point::ZERO;
@@ -116,6 +220,66 @@ mod tests {
         assert_eq!(restored, original);
     }
 
+    #[test]
+    fn create_from_arbitrary_matrix() {
+        let original = Point::new(1., 2., 3.);
+        #[rustfmt::skip]
+        let matrix = Matrix::new([
+            2., 0., 0., 1.,
+            0., 0., 3., 0.,
+            0., 1., 0., 0.,
+            0., 0., 0., 1.,
+        ]);
+        let transform = Transform::from_matrix(matrix).unwrap();
+
+        let transformed = transform.apply(&original);
+        let restored = transform.inverse().apply(&transformed);
+
+        assert_eq!(transformed, Point::new(3., 9., 2.));
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn singular_matrix_is_not_a_transform() {
+        let flatten = Matrix::diag(&Vector(1., 0., 1.));
+        assert!(Transform::from_matrix(flatten).is_none());
+    }
+
+    #[test]
+    fn small_scale_matrix_is_a_transform() {
+        let shrink = Matrix::diag(&Vector(1e-5, 1e-5, 1e-5));
+        let transform = Transform::from_matrix(shrink).unwrap();
+
+        let original = Point::new(1., 2., 3.);
+        assert_eq!(transform.inverse().apply(&transform.apply(&original)), original);
+    }
+
+    #[test]
+    fn apply_inverse_transform() {
+        let transform = Transform::translate(1., 2., 3.);
+
+        let restored = transform.apply_inverse(&Point::new(1., 2., 3.));
+        assert_eq!(restored, Point::ZERO)
+    }
+
+    #[test]
+    fn normal_is_not_affected_by_translation() {
+        let transform = Transform::translate(1., 2., 3.);
+
+        let normal = transform.apply_to_normal(&Vector(0., 1., 0.));
+        assert_eq!(normal, Normal::new(0., 1., 0.))
+    }
+
+    #[test]
+    fn normal_stays_perpendicular_under_non_uniform_scaling() {
+        let transform = Transform::scale(2., 1., 1.);
+        let tangent = transform.apply(&Vector(1., -1., 0.));
+
+        let normal = transform.apply_to_normal(&Vector(1., 1., 0.));
+        assert_eq!(normal, Normal::new(1., 2., 0.));
+        assert!(normal.dot(&tangent).abs() < 1e-9);
+    }
+
     mod compose_transforms {
         use super::*;
 
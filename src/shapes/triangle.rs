@@ -0,0 +1,222 @@
+use crate::geometry::{Aabb, Point, Ray, Vector};
+
+use super::{Intersection, Material, Shape, Surface, Transform};
+
+pub struct Triangle {
+    material: Material,
+    transform: Transform,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+}
+
+impl Triangle {
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Triangle {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+        let normal = e2.cross(&e1).normalize().v();
+        Triangle {
+            material: Material::default(),
+            transform: Transform::IDENTITY,
+            p1,
+            p2,
+            p3,
+            e1,
+            e2,
+            normal,
+        }
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
+
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn vertices(&self) -> [Point; 3] {
+        [self.p1, self.p2, self.p3]
+    }
+
+    pub fn is_degenerate(vertices: [Point; 3]) -> bool {
+        const EPSILON: f64 = 1.0e-12;
+
+        let [p1, p2, p3] = vertices;
+        let (e1, e2) = (p2 - p1, p3 - p1);
+        e1.cross(&e2).magnitude() <= EPSILON * e1.magnitude() * e2.magnitude()
+    }
+}
+
+impl Shape for Triangle {
+    fn material(&self) -> &Material {
+        &self.material
+    }
+
+    fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    fn local_intersect_with(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        intersect_triangle(ray, &self.p1, &self.e1, &self.e2)
+            .map(|(t, u, v)| Intersection::with_uv(t, u, v, self))
+            .into_iter()
+            .collect()
+    }
+
+    fn local_bounds(&self) -> Aabb {
+        Aabb::from_points(&self.vertices())
+    }
+}
+
+impl Surface for Triangle {
+    fn local_normal_at(&self, _pt: &Point, _hit: &Intersection) -> Vector {
+        self.normal
+    }
+}
+
+pub(super) fn intersect_triangle(
+    ray: &Ray,
+    p1: &Point,
+    e1: &Vector,
+    e2: &Vector,
+) -> Option<(f64, f64, f64)> {
+    const EPSILON: f64 = 1.0e-12;
+
+    let dir_cross_e2 = ray.direction.cross(e2);
+    let det = e1.dot(&dir_cross_e2);
+    let scale = e1.magnitude() * e2.magnitude() * ray.direction.magnitude();
+    if det.abs() <= EPSILON * scale {
+        return None;
+    }
+
+    let f = det.recip();
+    let p1_to_origin = ray.origin - *p1;
+    let u = f * p1_to_origin.dot(&dir_cross_e2);
+    if !(0. ..=1.).contains(&u) {
+        return None;
+    }
+
+    let origin_cross_e1 = p1_to_origin.cross(e1);
+    let v = f * ray.direction.dot(&origin_cross_e1);
+    if v < 0. || u + v > 1. {
+        return None;
+    }
+
+    Some((f * e2.dot(&origin_cross_e1), u, v))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Normal, UnitVector};
+
+    use super::*;
+
+    fn triangle() -> Triangle {
+        Triangle::new(
+            Point::new(0., 1., 0.),
+            Point::new(-1., 0., 0.),
+            Point::new(1., 0., 0.),
+        )
+    }
+
+    #[test]
+    pub fn precompute_edges_and_normal() {
+        let t = triangle();
+
+        assert_eq!(t.e1, Vector(-1., -1., 0.));
+        assert_eq!(t.e2, Vector(1., -1., 0.));
+        assert_eq!(t.normal, Vector(0., 0., -1.));
+    }
+
+    #[test]
+    pub fn normal_is_constant() {
+        let t = triangle();
+        let hit = Intersection::new(0., &t);
+        let expected = Normal::new(0., 0., -1.);
+
+        assert_eq!(t.normal_at(&Point::new(0., 0.5, 0.), &hit), expected);
+        assert_eq!(t.normal_at(&Point::new(-0.5, 0.75, 0.), &hit), expected);
+        assert_eq!(t.normal_at(&Point::new(0.5, 0.25, 0.), &hit), expected);
+    }
+
+    #[test]
+    pub fn ray_parallel_to_triangle_misses() {
+        let ray = Ray::new(Point::new(0., -1., -2.), UnitVector::Y);
+        assert!(triangle().intersect_with(&ray).is_empty());
+    }
+
+    #[test]
+    pub fn ray_misses_each_edge() {
+        for origin in [
+            Point::new(1., 1., -2.),
+            Point::new(-1., 1., -2.),
+            Point::new(0., -1., -2.),
+        ] {
+            let ray = Ray::new(origin, UnitVector::Z);
+            assert!(triangle().intersect_with(&ray).is_empty());
+        }
+    }
+
+    #[test]
+    pub fn ray_strikes_triangle() {
+        let ray = Ray::new(Point::new(0., 0.5, -2.), UnitVector::Z);
+
+        let t = triangle();
+        let xs = t.intersect_with(&ray);
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 2.);
+    }
+
+    #[test]
+    pub fn ray_strikes_tiny_triangle() {
+        let t = Triangle::new(
+            Point::new(0., 1e-7, 0.),
+            Point::new(-1e-7, 0., 0.),
+            Point::new(1e-7, 0., 0.),
+        );
+        let ray = Ray::new(Point::new(0., 5e-8, -2.), UnitVector::Z);
+
+        let xs = t.intersect_with(&ray);
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].t, 2.);
+    }
+
+    #[test]
+    pub fn intersection_reports_barycentric_coordinates() {
+        let ray = Ray::new(Point::new(-0.2, 0.3, -2.), UnitVector::Z);
+
+        let t = triangle();
+        let xs = t.intersect_with(&ray);
+        assert!((xs[0].u - 0.45).abs() < 1e-9);
+        assert!((xs[0].v - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    pub fn zero_area_triangles_are_degenerate() {
+        assert!(!Triangle::is_degenerate(triangle().vertices()));
+        assert!(Triangle::is_degenerate([
+            Point::ZERO,
+            Point::ZERO,
+            Point::new(1., 0., 0.)
+        ]));
+        assert!(Triangle::is_degenerate([
+            Point::new(0., 0., 0.),
+            Point::new(1., 1., 1.),
+            Point::new(3., 3., 3.),
+        ]));
+    }
+
+    #[test]
+    pub fn bounds_enclose_vertices() {
+        assert_eq!(
+            triangle().bounds(),
+            Aabb::new(Point::new(-1., 0., 0.), Point::new(1., 1., 0.))
+        );
+    }
+}